@@ -1,19 +1,65 @@
-use wgpu::util::DeviceExt;
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    path::Path,
+};
+
 use winit::window::Window;
 
 use crate::{
-    object::Object,
+    constants::{
+        DEFAULT_CREASE_ANGLE,
+        DEFAULT_TOLERANCE,
+        DEFAULT_WELD_DISTANCE,
+        INSTANCE_HIGHLIGHT,
+        INSTANCE_HOVERED,
+        INSTANCE_SELECTED,
+    },
+    glyph_registry::GlyphRegistry,
+    object::{Bevel, BevelProfile, Changes, Pivot, ScaleSpace, Shading},
     orthographic_view::{Orientation, OrthographicView},
     perspective_view::PerspectiveView,
-    types::{
-        FaceType,
-        ShaderVertex,
-    }
+    pipelines::RenderMode,
+    rotation::{from_axis_angle, from_euler, rotate, to_axis_angle, to_euler, RotationOrder, Space},
+    text::{Alignment, Text},
+    scene::Scene,
+    types::{ShaderInstance, ShaderVertex},
 };
 
 pub const SIDEBAR_WIDTH: u32 = 320;
 pub const VIEWS_SPACING: u32 = 10;
 
+// id do cursor na cena (os objetos recebem ids a partir de zero)
+const CARET_ID: usize = usize::MAX;
+
+// o contorno de destaque de cada objeto usa na cena o id do objeto somado a
+// este valor
+const OUTLINE_IDS: usize = usize::MAX / 2;
+
+// folga entre o objeto e o seu contorno de destaque
+const OUTLINE_MARGIN: f64 = 0.02;
+
+// matriz das normais do cursor e dos contornos, que não têm faces
+const IDENTITY: [[f32; 3]; 3] = [
+    [1.0, 0.0, 0.0],
+    [0.0, 1.0, 0.0],
+    [0.0, 0.0, 1.0],
+];
+
+// eixos da cena que cada vista mostra na horizontal e na vertical
+const FRONT_AXES: [usize; 2] = [0, 1];
+const SIDE_AXES: [usize; 2] = [2, 1];
+const TOP_AXES: [usize; 2] = [0, 2];
+
+const DEFAULT_BEVEL: Bevel = Bevel {
+    depth: 0.05,
+    width: 0.03,
+    segments: 3,
+    profile: BevelProfile::Rounded,
+};
+
 #[derive(Debug, PartialEq)]
 enum Visualization {
     All,
@@ -23,12 +69,29 @@ enum Visualization {
     Perspective,
 }
 
+// forma de editar a rotação do objeto selecionado
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RotationEditor {
+    Euler,
+    AxisAngle,
+    Incremental,
+}
+
+// forma de destacar os objetos selecionados e o objeto sob o mouse
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Highlight {
+    // muda a cor do próprio objeto
+    Recolor,
+    // desenha uma caixa em volta do objeto, que mantém a cor
+    Outline,
+}
+
 pub struct Application {
     // wgpu
-    instance: wgpu::Instance,
+    _instance: wgpu::Instance,
     surface_config: wgpu::SurfaceConfiguration,
     surface: wgpu::Surface,
-    adapter: wgpu::Adapter,
+    _adapter: wgpu::Adapter,
     device: wgpu::Device,
     queue: wgpu::Queue,
     // vistas
@@ -36,12 +99,14 @@ pub struct Application {
     side_view: OrthographicView,
     top_view: OrthographicView,
     pub perspective_view: PerspectiveView,
-    // lista de objetos
-    objects: Vec<Object>,
+    // glifos disponíveis
+    pub glyph_registry: GlyphRegistry,
+    // texto e seus objetos
+    text: Text,
     // buffers
-    vertices_buffer: wgpu::Buffer,
-    lines_indices_buffer: wgpu::Buffer,
-    lines_indices_len: u32,
+    scene: Scene,
+    // estado (bits INSTANCE_*) de cada objeto no último envio à GPU
+    scene_states: HashMap<usize, u32>,
     // egui
     platform: egui_winit_platform::Platform,
     render_pass: egui_wgpu_backend::RenderPass,
@@ -49,10 +114,24 @@ pub struct Application {
     previous_frame_time: Option<f32>,
     // gui state
     visualization: Visualization,
-    pub selected: Option<usize>,
+    font_path_string: String,
+    font_loading_error: Option<String>,
+    pub caret: usize,
+    selected: Option<usize>,
+    // ids de todos os objetos selecionados, incluindo o de `selected`
+    selection: HashSet<usize>,
+    // id do objeto sob o mouse em alguma das vistas
+    hovered: Option<usize>,
+    highlight: Highlight,
+    render_mode: RenderMode,
     extrusion: bool,
     extrusion_string: String,
     extrusion_string_parsing_error: bool,
+    bevel: bool,
+    bevel_depth: f64,
+    bevel_width: f64,
+    bevel_segments: usize,
+    bevel_profile: BevelProfile,
     x_scale_string: String,
     y_scale_string: String,
     z_scale_string: String,
@@ -61,9 +140,28 @@ pub struct Application {
     y_position_string: String,
     z_position_string: String,
     position_string_parsing_error: bool,
+    rotation_editor: RotationEditor,
+    rotation_order: RotationOrder,
     x_rotation: f64,
     y_rotation: f64,
     z_rotation: f64,
+    x_axis_string: String,
+    y_axis_string: String,
+    z_axis_string: String,
+    axis_string_parsing_error: bool,
+    rotation_angle: f64,
+    rotation_space: Space,
+    rotation_step: f64,
+    pivot: Pivot,
+    x_pivot_string: String,
+    y_pivot_string: String,
+    z_pivot_string: String,
+    pivot_string_parsing_error: bool,
+    scale_space: ScaleSpace,
+    tolerance: f64,
+    weld_distance: f64,
+    shading: Shading,
+    crease_angle: f64,
 }
 
 impl Application {
@@ -168,47 +266,46 @@ impl Application {
             views_height
         );
 
-        let objects: Vec<Object> = Vec::new();
-
-        let vertices: Vec<ShaderVertex> = Vec::new();
-        let lines_indices: Vec<u32> = Vec::new();
-
-        let vertices_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-            label: Some("vertex-buffer"),
-            contents: bytemuck::cast_slice(&vertices),
-            usage: wgpu::BufferUsages::VERTEX,
-        });
+        let text = Text::new();
 
-        let lines_indices_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-            label: Some("line-indices-buffer"),
-            contents: bytemuck::cast_slice(&lines_indices),
-            usage: wgpu::BufferUsages::INDEX,
-        });
+        let scene = Scene::new(&device);
 
         Self {
-            instance,
+            _instance: instance,
             surface_config: config,
             surface,
-            adapter,
+            _adapter: adapter,
             device,
             queue,
             front_view,
             side_view,
             top_view,
             perspective_view,
-            objects,
-            vertices_buffer,
-            lines_indices_buffer,
-            lines_indices_len: 0,
+            glyph_registry: GlyphRegistry::new(),
+            text,
+            scene,
+            scene_states: HashMap::new(),
             platform,
             render_pass,
             start_time: std::time::Instant::now(),
             previous_frame_time: None,
             visualization: Visualization::All,
+            font_path_string: String::new(),
+            font_loading_error: None,
+            caret: 0,
             selected: None,
+            selection: HashSet::new(),
+            hovered: None,
+            highlight: Highlight::Recolor,
+            render_mode: RenderMode::Wireframe,
             extrusion: false,
             extrusion_string: String::new(),
             extrusion_string_parsing_error: false,
+            bevel: false,
+            bevel_depth: DEFAULT_BEVEL.depth,
+            bevel_width: DEFAULT_BEVEL.width,
+            bevel_segments: DEFAULT_BEVEL.segments,
+            bevel_profile: DEFAULT_BEVEL.profile,
             x_position_string: String::new(),
             y_position_string: String::new(),
             z_position_string: String::new(),
@@ -217,9 +314,28 @@ impl Application {
             y_scale_string: String::new(),
             z_scale_string: String::new(),
             scale_string_parsing_error: false,
+            rotation_editor: RotationEditor::Euler,
+            rotation_order: RotationOrder::Xyz,
             x_rotation: 0.0,
             y_rotation: 0.0,
             z_rotation: 0.0,
+            x_axis_string: String::new(),
+            y_axis_string: String::new(),
+            z_axis_string: String::new(),
+            axis_string_parsing_error: false,
+            rotation_angle: 0.0,
+            rotation_space: Space::World,
+            rotation_step: 15.0,
+            pivot: Pivot::Origin,
+            x_pivot_string: String::new(),
+            y_pivot_string: String::new(),
+            z_pivot_string: String::new(),
+            pivot_string_parsing_error: false,
+            scale_space: ScaleSpace::Rotated,
+            tolerance: DEFAULT_TOLERANCE,
+            weld_distance: DEFAULT_WELD_DISTANCE,
+            shading: Shading::Smooth,
+            crease_angle: DEFAULT_CREASE_ANGLE,
         }
     }
 
@@ -356,6 +472,121 @@ impl Application {
                         }
                     });
 
+                    ui.horizontal(|ui| {
+                        ui.label("Desenho:");
+                        egui::ComboBox::from_id_source("render-mode")
+                            .selected_text(match self.render_mode {
+                                RenderMode::Wireframe => "Arame",
+                                RenderMode::Solid => "Sólido",
+                                RenderMode::SolidWireframe => "Sólido com arame",
+                            })
+                            .show_ui(ui, |ui| {
+                                ui.selectable_value(&mut self.render_mode, RenderMode::Wireframe, "Arame");
+                                ui.selectable_value(&mut self.render_mode, RenderMode::Solid, "Sólido");
+                                ui.selectable_value(&mut self.render_mode, RenderMode::SolidWireframe, "Sólido com arame");
+                            });
+                    });
+
+                    ui.horizontal(|ui| {
+                        ui.label("Destaque:");
+                        let highlight = self.highlight;
+                        egui::ComboBox::from_id_source("highlight")
+                            .selected_text(match self.highlight {
+                                Highlight::Recolor => "Cor",
+                                Highlight::Outline => "Contorno",
+                            })
+                            .show_ui(ui, |ui| {
+                                ui.selectable_value(&mut self.highlight, Highlight::Recolor, "Cor");
+                                ui.selectable_value(&mut self.highlight, Highlight::Outline, "Contorno");
+                            });
+                        if self.highlight != highlight {
+                            self.update_scene();
+                        }
+                    });
+
+                    ui.vertical(|ui| {
+                        ui.spacing_mut().item_spacing.y = 2.0;
+                        ui.horizontal(|ui| {
+                            ui.label("Fonte:");
+                            let mut font = self.glyph_registry.font();
+                            let font_names = self.glyph_registry.font_names();
+                            egui::ComboBox::from_id_source("font")
+                                .selected_text(match font {
+                                    Some(index) => font_names[index],
+                                    None => "Interna",
+                                })
+                                .show_ui(ui, |ui| {
+                                    ui.selectable_value(&mut font, None, "Interna");
+                                    for (index, name) in font_names.iter().enumerate() {
+                                        ui.selectable_value(&mut font, Some(index), *name);
+                                    }
+                                });
+                            self.glyph_registry.select_font(font);
+                        });
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.font_path_string)
+                                .hint_text("Arquivo .ttf ou .otf")
+                        );
+                        if let Some(error) = &self.font_loading_error {
+                            ui.label(error.as_str());
+                        }
+                        if ui.button("Carregar").clicked() {
+                            match self.glyph_registry.load_font(Path::new(&self.font_path_string)) {
+                                Ok(font) => {
+                                    self.glyph_registry.select_font(Some(font));
+                                    self.font_loading_error = None;
+                                }
+                                Err(error) => {
+                                    self.font_loading_error = Some(error.to_string());
+                                }
+                            }
+                        }
+                    });
+
+                    ui.vertical(|ui| {
+                        ui.spacing_mut().item_spacing.y = 2.0;
+                        ui.horizontal(|ui| {
+                            ui.label("Alinhamento:");
+                            let mut alignment = self.text.alignment;
+                            egui::ComboBox::from_id_source("alignment")
+                                .selected_text(match alignment {
+                                    Alignment::Left => "Esquerda",
+                                    Alignment::Center => "Centro",
+                                    Alignment::Right => "Direita",
+                                    Alignment::Justified => "Justificado",
+                                })
+                                .show_ui(ui, |ui| {
+                                    ui.selectable_value(&mut alignment, Alignment::Left, "Esquerda");
+                                    ui.selectable_value(&mut alignment, Alignment::Center, "Centro");
+                                    ui.selectable_value(&mut alignment, Alignment::Right, "Direita");
+                                    ui.selectable_value(&mut alignment, Alignment::Justified, "Justificado");
+                                });
+                            if alignment != self.text.alignment {
+                                self.text.set_alignment(alignment, &self.glyph_registry);
+                                self.update();
+                            }
+                        });
+                        ui.label("Altura da linha:");
+                        let mut line_height = self.text.line_height;
+                        if ui.add(egui::Slider::new(&mut line_height, 0.5..=3.0)).changed() {
+                            self.text.set_line_height(line_height, &self.glyph_registry);
+                            self.update();
+                        }
+                        ui.label("Espaçamento entre letras:");
+                        let mut tracking = self.text.tracking;
+                        if ui.add(egui::Slider::new(&mut tracking, -0.3..=0.5)).changed() {
+                            self.text.set_tracking(tracking, &self.glyph_registry);
+                            self.update();
+                        }
+                    });
+
+                    if self.selection.len() > 1 {
+                        ui.label(format!(
+                            "{} letras selecionadas; as mudanças valem para a letra antes do cursor",
+                            self.selection.len(),
+                        ));
+                    }
+
                     if let Some(selected) = self.selected {
                         ui.vertical(|ui| {
                             ui.spacing_mut().item_spacing.y = 2.0;
@@ -367,8 +598,13 @@ impl Application {
                                 }
                                 ui.horizontal(|ui| {
                                     if ui.button("Aplicar").clicked() {
-                                        if let Ok(extrusion) = self.extrusion_string.parse::<f64>() {
-                                            self.objects[selected].set_extrusion(Some(extrusion));
+                                        // só aceita extrusões positivas e finitas
+                                        let extrusion = self.extrusion_string
+                                            .parse::<f64>()
+                                            .ok()
+                                            .filter(|extrusion| extrusion.is_finite() && *extrusion > 0.0);
+                                        if let Some(extrusion) = extrusion {
+                                            self.text.object_mut(selected).set_extrusion(Some(extrusion));
                                             self.update();
                                             self.extrusion_string_parsing_error = false;
                                         } else {
@@ -376,7 +612,7 @@ impl Application {
                                         }
                                     }
                                     if ui.button("Restaurar").clicked() {
-                                        match self.objects[selected].extrusion {
+                                        match self.text.object(selected).extrusion {
                                             Some(extrusion) => {
                                                 self.extrusion_string = format!(
                                                     "{}",
@@ -390,9 +626,59 @@ impl Application {
                                         }
                                     }
                                 });
+                                if self.text.object(selected).extrusion.is_some() {
+                                    let mut changed = ui.checkbox(&mut self.bevel, "Chanfro").changed();
+                                    if self.bevel {
+                                        ui.label("Profundidade do chanfro:");
+                                        changed |= ui.add(egui::Slider::new(
+                                            &mut self.bevel_depth, 0.0..=0.5
+                                        )).changed();
+                                        ui.label("Largura do chanfro:");
+                                        changed |= ui.add(egui::Slider::new(
+                                            &mut self.bevel_width, 0.0..=0.1
+                                        )).changed();
+                                        ui.label("Segmentos do chanfro:");
+                                        changed |= ui.add(egui::Slider::new(
+                                            &mut self.bevel_segments, 1..=8
+                                        )).changed();
+                                        ui.horizontal(|ui| {
+                                            ui.label("Perfil:");
+                                            egui::ComboBox::from_id_source("bevel-profile")
+                                                .selected_text(match self.bevel_profile {
+                                                    BevelProfile::Flat => "Reto",
+                                                    BevelProfile::Rounded => "Arredondado",
+                                                })
+                                                .show_ui(ui, |ui| {
+                                                    changed |= ui.selectable_value(
+                                                        &mut self.bevel_profile,
+                                                        BevelProfile::Flat,
+                                                        "Reto",
+                                                    ).clicked();
+                                                    changed |= ui.selectable_value(
+                                                        &mut self.bevel_profile,
+                                                        BevelProfile::Rounded,
+                                                        "Arredondado",
+                                                    ).clicked();
+                                                });
+                                        });
+                                    }
+                                    if changed {
+                                        self.text.object_mut(selected).set_bevel(if self.bevel {
+                                            Some(Bevel {
+                                                depth: self.bevel_depth,
+                                                width: self.bevel_width,
+                                                segments: self.bevel_segments,
+                                                profile: self.bevel_profile,
+                                            })
+                                        } else {
+                                            None
+                                        });
+                                        self.update();
+                                    }
+                                }
                             } else {
-                                if self.objects[selected].extrusion.is_some() {
-                                    self.objects[selected].set_extrusion(None);
+                                if self.text.object(selected).extrusion.is_some() {
+                                    self.text.object_mut(selected).set_extrusion(None);
                                     self.update();
                                 }
                             }
@@ -418,7 +704,7 @@ impl Application {
                                         self.y_position_string.parse::<f64>(),
                                         self.z_position_string.parse::<f64>(),
                                     ) {
-                                        self.objects[selected].set_position([
+                                        self.text.object_mut(selected).set_position([
                                             xp,
                                             yp,
                                             zp,
@@ -433,15 +719,15 @@ impl Application {
                                 if ui.button("Restaurar").clicked() {
                                     self.x_position_string = format!(
                                         "{}",
-                                        self.objects[selected].position[0],
+                                        self.text.object(selected).position[0],
                                     );
                                     self.y_position_string = format!(
                                         "{}",
-                                        self.objects[selected].position[1],
+                                        self.text.object(selected).position[1],
                                     );
                                     self.z_position_string = format!(
                                         "{}",
-                                        self.objects[selected].position[2],
+                                        self.text.object(selected).position[2],
                                     );
                                 }
                             });
@@ -467,7 +753,7 @@ impl Application {
                                         self.y_scale_string.parse::<f64>(),
                                         self.z_scale_string.parse::<f64>(),
                                     ) {
-                                        self.objects[selected].set_scale([
+                                        self.text.object_mut(selected).set_scale([
                                             xs,
                                             ys,
                                             zs,
@@ -481,15 +767,15 @@ impl Application {
                                 if ui.button("Restaurar").clicked() {
                                     self.x_scale_string = format!(
                                         "{}",
-                                        self.objects[selected].scale[0],
+                                        self.text.object(selected).scale[0],
                                     );
                                     self.y_scale_string = format!(
                                         "{}",
-                                        self.objects[selected].scale[1],
+                                        self.text.object(selected).scale[1],
                                     );
                                     self.z_scale_string = format!(
                                         "{}",
-                                        self.objects[selected].scale[2],
+                                        self.text.object(selected).scale[2],
                                     );
                                 }
                             });
@@ -497,42 +783,276 @@ impl Application {
 
                         ui.vertical(|ui| {
                             ui.spacing_mut().item_spacing.y = 2.0;
-                            ui.label("Rotação:");
-                            if ui.add(egui::Slider::new(
-                                &mut self.x_rotation, -180.0..=180.0
-                            )).changed() {
-                                self.objects[selected].set_rotation([
-                                    self.x_rotation,
-                                    self.y_rotation,
-                                    self.z_rotation,
-                                ]);
-                                self.update();
+                            ui.horizontal(|ui| {
+                                ui.label("Rotação:");
+                                egui::ComboBox::from_id_source("rotation-editor")
+                                    .selected_text(match self.rotation_editor {
+                                        RotationEditor::Euler => "Ângulos de Euler",
+                                        RotationEditor::AxisAngle => "Eixo e ângulo",
+                                        RotationEditor::Incremental => "Incremental",
+                                    })
+                                    .show_ui(ui, |ui| {
+                                        ui.selectable_value(&mut self.rotation_editor, RotationEditor::Euler, "Ângulos de Euler");
+                                        ui.selectable_value(&mut self.rotation_editor, RotationEditor::AxisAngle, "Eixo e ângulo");
+                                        ui.selectable_value(&mut self.rotation_editor, RotationEditor::Incremental, "Incremental");
+                                    });
+                            });
+
+                            match self.rotation_editor {
+                                RotationEditor::Euler => {
+                                    ui.horizontal(|ui| {
+                                        ui.label("Ordem:");
+                                        let order = self.rotation_order;
+                                        egui::ComboBox::from_id_source("rotation-order")
+                                            .selected_text(self.rotation_order.name())
+                                            .show_ui(ui, |ui| {
+                                                for order in RotationOrder::ALL {
+                                                    ui.selectable_value(&mut self.rotation_order, order, order.name());
+                                                }
+                                            });
+                                        // mantém a rotação e recalcula os ângulos na nova ordem
+                                        if self.rotation_order != order {
+                                            self.update();
+                                        }
+                                    });
+                                    let mut changed = false;
+                                    for (angle, name) in [
+                                        (&mut self.x_rotation, "X"),
+                                        (&mut self.y_rotation, "Y"),
+                                        (&mut self.z_rotation, "Z"),
+                                    ] {
+                                        changed |= ui.add(egui::Slider::new(
+                                            angle, -180.0..=180.0
+                                        ).text(name)).changed();
+                                    }
+                                    if changed {
+                                        self.text.object_mut(selected).set_rotation(from_euler(
+                                            [self.x_rotation, self.y_rotation, self.z_rotation],
+                                            self.rotation_order,
+                                        ));
+                                        self.update();
+                                    }
+                                }
+                                RotationEditor::AxisAngle => {
+                                    ui.label("Eixo:");
+                                    ui.add(egui::TextEdit::singleline(&mut self.x_axis_string));
+                                    ui.add(egui::TextEdit::singleline(&mut self.y_axis_string));
+                                    ui.add(egui::TextEdit::singleline(&mut self.z_axis_string));
+                                    if self.axis_string_parsing_error {
+                                        ui.label("O eixo precisa ter três pontos flutuantes e não ser nulo!");
+                                    }
+                                    ui.label("Ângulo:");
+                                    ui.add(egui::Slider::new(&mut self.rotation_angle, -180.0..=180.0));
+                                    ui.horizontal(|ui| {
+                                        if ui.button("Aplicar").clicked() {
+                                            let rotation = match (
+                                                self.x_axis_string.parse::<f64>(),
+                                                self.y_axis_string.parse::<f64>(),
+                                                self.z_axis_string.parse::<f64>(),
+                                            ) {
+                                                (Ok(x), Ok(y), Ok(z)) => from_axis_angle([x, y, z], self.rotation_angle),
+                                                _ => None,
+                                            };
+                                            if let Some(rotation) = rotation {
+                                                self.text.object_mut(selected).set_rotation(rotation);
+                                                self.update();
+                                                self.axis_string_parsing_error = false;
+                                            } else {
+                                                self.axis_string_parsing_error = true;
+                                            }
+                                        }
+                                        if ui.button("Restaurar").clicked() {
+                                            self.axis_string_parsing_error = false;
+                                            self.update();
+                                        }
+                                    });
+                                }
+                                RotationEditor::Incremental => {
+                                    ui.horizontal(|ui| {
+                                        ui.label("Eixos:");
+                                        egui::ComboBox::from_id_source("rotation-space")
+                                            .selected_text(match self.rotation_space {
+                                                Space::World => "Do mundo",
+                                                Space::Local => "Do objeto",
+                                            })
+                                            .show_ui(ui, |ui| {
+                                                ui.selectable_value(&mut self.rotation_space, Space::World, "Do mundo");
+                                                ui.selectable_value(&mut self.rotation_space, Space::Local, "Do objeto");
+                                            });
+                                    });
+                                    ui.label("Passo:");
+                                    ui.add(egui::Slider::new(&mut self.rotation_step, 1.0..=90.0));
+                                    ui.horizontal(|ui| {
+                                        for (index, name) in ["X", "Y", "Z"].iter().enumerate() {
+                                            for (sign, direction) in [(-1.0, "-"), (1.0, "+")] {
+                                                if ui.button(format!("{}{}", name, direction)).clicked() {
+                                                    let rotation = rotate(
+                                                        &self.text.object(selected).rotation,
+                                                        index,
+                                                        sign * self.rotation_step,
+                                                        self.rotation_space,
+                                                    );
+                                                    self.text.object_mut(selected).set_rotation(rotation);
+                                                    self.update();
+                                                }
+                                            }
+                                        }
+                                    });
+                                }
+                            }
+                        });
+
+                        ui.vertical(|ui| {
+                            ui.spacing_mut().item_spacing.y = 2.0;
+                            ui.horizontal(|ui| {
+                                ui.label("Pivô:");
+                                let pivot = self.pivot;
+                                egui::ComboBox::from_id_source("pivot")
+                                    .selected_text(match self.pivot {
+                                        Pivot::Origin => "Origem",
+                                        Pivot::BoundingBoxCenter => "Centro da caixa",
+                                        Pivot::Cursor(_) => "Cursor",
+                                        Pivot::Custom(_) => "Personalizado",
+                                    })
+                                    .show_ui(ui, |ui| {
+                                        if ui.selectable_label(self.pivot == Pivot::Origin, "Origem").clicked() {
+                                            self.pivot = Pivot::Origin;
+                                        }
+                                        if ui.selectable_label(self.pivot == Pivot::BoundingBoxCenter, "Centro da caixa").clicked() {
+                                            self.pivot = Pivot::BoundingBoxCenter;
+                                        }
+                                        if ui.selectable_label(matches!(self.pivot, Pivot::Cursor(_)), "Cursor").clicked() {
+                                            let [x, y] = self.text.caret_position(self.caret);
+                                            self.pivot = Pivot::Cursor([x, y, 0.0]);
+                                        }
+                                        // começa no pivô atual para o objeto não saltar
+                                        if ui.selectable_label(matches!(self.pivot, Pivot::Custom(_)), "Personalizado").clicked() &&
+                                            !matches!(self.pivot, Pivot::Custom(_))
+                                        {
+                                            self.pivot = Pivot::Custom(self.text.object(selected).pivot_point());
+                                        }
+                                    });
+                                if self.pivot != pivot {
+                                    self.text.object_mut(selected).set_pivot(self.pivot);
+                                    self.update();
+                                }
+                            });
+                            if let Pivot::Custom(_) = self.pivot {
+                                ui.add(egui::TextEdit::singleline(&mut self.x_pivot_string));
+                                ui.add(egui::TextEdit::singleline(&mut self.y_pivot_string));
+                                ui.add(egui::TextEdit::singleline(&mut self.z_pivot_string));
+                                if self.pivot_string_parsing_error {
+                                    ui.label("Algo aqui não é ponto flutuante!");
+                                }
+                                if ui.button("Aplicar").clicked() {
+                                    if let (
+                                        Ok(xp),
+                                        Ok(yp),
+                                        Ok(zp),
+                                    ) = (
+                                        self.x_pivot_string.parse::<f64>(),
+                                        self.y_pivot_string.parse::<f64>(),
+                                        self.z_pivot_string.parse::<f64>(),
+                                    ) {
+                                        self.text.object_mut(selected).set_pivot(Pivot::Custom([xp, yp, zp]));
+                                        self.update();
+                                        self.pivot_string_parsing_error = false;
+                                    } else {
+                                        self.pivot_string_parsing_error = true;
+                                    }
+                                }
                             }
+                            ui.horizontal(|ui| {
+                                ui.label("Escala nos eixos:");
+                                let scale_space = self.scale_space;
+                                egui::ComboBox::from_id_source("scale-space")
+                                    .selected_text(match self.scale_space {
+                                        ScaleSpace::Local => "Do objeto",
+                                        ScaleSpace::Rotated => "Da cena",
+                                    })
+                                    .show_ui(ui, |ui| {
+                                        ui.selectable_value(&mut self.scale_space, ScaleSpace::Local, "Do objeto");
+                                        ui.selectable_value(&mut self.scale_space, ScaleSpace::Rotated, "Da cena");
+                                    });
+                                if self.scale_space != scale_space {
+                                    self.text.object_mut(selected).set_scale_space(self.scale_space);
+                                    self.update();
+                                }
+                            });
+                        });
+
+                        ui.vertical(|ui| {
+                            ui.spacing_mut().item_spacing.y = 2.0;
+                            ui.label("Tolerância da tesselação:");
                             if ui.add(egui::Slider::new(
-                                &mut self.y_rotation, -180.0..=180.0
-                            )).changed() {
-                                self.objects[selected].set_rotation([
-                                    self.x_rotation,
-                                    self.y_rotation,
-                                    self.z_rotation,
-                                ]);
+                                &mut self.tolerance, 0.0005..=0.05
+                            ).logarithmic(true)).changed() {
+                                self.text.object_mut(selected).set_tolerance(self.tolerance);
                                 self.update();
                             }
-                            if ui.add(egui::Slider::new(
-                                &mut self.z_rotation, -180.0..=180.0
-                            )).changed() {
-                                self.objects[selected].set_rotation([
-                                    self.x_rotation,
-                                    self.y_rotation,
-                                    self.z_rotation,
-                                ]);
+                            ui.label("Distância de fusão:");
+                            ui.add(egui::Slider::new(
+                                &mut self.weld_distance, 0.000001..=0.05
+                            ).logarithmic(true));
+                            if ui.button("Mesclar por distância").clicked() {
+                                self.text.object_mut(selected).merge_by_distance(self.weld_distance);
                                 self.update();
                             }
+                            ui.label(format!(
+                                "Vértices: {}",
+                                self.text.object(selected).vertex_count(),
+                            ));
+                            let report = self.text.object(selected).report();
+                            if report.is_valid() {
+                                ui.label("Malha sem problemas");
+                            } else {
+                                ui.label(format!(
+                                    "Problemas na malha: {}",
+                                    report.problems.len(),
+                                ));
+                                // lista só os primeiros para não ocupar a barra lateral
+                                for problem in report.problems.iter().take(5) {
+                                    ui.colored_label(egui::Color32::RED, problem.to_string());
+                                }
+                            }
+                        });
+
+                        ui.vertical(|ui| {
+                            ui.spacing_mut().item_spacing.y = 2.0;
+                            ui.horizontal(|ui| {
+                                ui.label("Sombreamento:");
+                                let shading = self.shading;
+                                egui::ComboBox::from_id_source("shading")
+                                    .selected_text(match self.shading {
+                                        Shading::Flat => "Plano",
+                                        Shading::Smooth => "Suave",
+                                    })
+                                    .show_ui(ui, |ui| {
+                                        ui.selectable_value(&mut self.shading, Shading::Flat, "Plano");
+                                        ui.selectable_value(&mut self.shading, Shading::Smooth, "Suave");
+                                    });
+                                if self.shading != shading {
+                                    self.text.object_mut(selected).set_shading(self.shading);
+                                    self.update();
+                                }
+                            });
+                            if self.shading == Shading::Smooth {
+                                ui.label("Ângulo de vinco:");
+                                if ui.add(egui::Slider::new(
+                                    &mut self.crease_angle, 0.0..=180.0
+                                )).changed() {
+                                    self.text.object_mut(selected).set_crease_angle(self.crease_angle);
+                                    self.update();
+                                }
+                            }
                         });
                     }
                 });
             });
 
+        // respostas das vistas ao mouse, com os eixos que cada uma mostra
+        let mut responses: Vec<(egui::Response, [usize; 2])> = Vec::new();
+
         egui::CentralPanel::default()
             .show(ctx, |ui| match self.visualization {
                 Visualization::All => {
@@ -540,138 +1060,219 @@ impl Application {
                         ui.spacing_mut().item_spacing.y = VIEWS_SPACING as f32;
                         ui.horizontal(|ui| {
                             ui.spacing_mut().item_spacing.x = VIEWS_SPACING as f32;
-                            self.front_view.show(ui, &self.device);
-                            self.side_view.show(ui, &self.device);
+                            responses.push((self.front_view.show(ui, &self.device), FRONT_AXES));
+                            responses.push((self.side_view.show(ui, &self.device), SIDE_AXES));
                         });
                         ui.horizontal(|ui| {
                             ui.spacing_mut().item_spacing.x = VIEWS_SPACING as f32;
-                            self.top_view.show(ui, &self.device);
-                            self.perspective_view.show(ui, &self.device);
+                            responses.push((self.top_view.show(ui, &self.device), TOP_AXES));
+                            // a perspectiva ainda projeta como a vista de frente
+                            responses.push((self.perspective_view.show(ui, &self.device), FRONT_AXES));
                         });
                     });
                 }
                 Visualization::Front => {
-                    self.front_view.show(ui, &self.device);
+                    responses.push((self.front_view.show(ui, &self.device), FRONT_AXES));
                 }
                 Visualization::Side => {
-                    self.side_view.show(ui, &self.device);
+                    responses.push((self.side_view.show(ui, &self.device), SIDE_AXES));
                 }
                 Visualization::Top => {
-                    self.top_view.show(ui, &self.device);
+                    responses.push((self.top_view.show(ui, &self.device), TOP_AXES));
                 }
                 Visualization::Perspective => {
-                    self.perspective_view.show(ui, &self.device);
+                    responses.push((self.perspective_view.show(ui, &self.device), FRONT_AXES));
                 }
             });
-    }
 
-    pub fn inc_selected(&mut self) {
-        if self.selected.is_none() {
-            if self.objects.len() > 0 {
-                self.selected = Some(0);
-            }
-        } else {
-            let selected = self.selected.unwrap();
-            if selected + 1 < self.objects.len() {
-                self.selected = Some(selected + 1);
+        // um clique seleciona a letra e põe o cursor depois dela; com Ctrl,
+        // acrescenta a letra à seleção ou a retira dela
+        let mut hovered = None;
+        for (response, axes) in responses.iter() {
+            if let Some(index) = self.pick(response, *axes) {
+                let id = self.text.object(index).id();
+                hovered = Some(id);
+                if response.clicked() {
+                    if ctx.input().modifiers.command {
+                        if !self.selection.remove(&id) {
+                            self.selection.insert(id);
+                        }
+                        self.update_scene();
+                    } else {
+                        self.set_caret(index + 1, false);
+                    }
+                }
             }
         }
 
-        self.update();
+        if hovered != self.hovered {
+            self.hovered = hovered;
+            self.update_scene();
+        }
     }
 
-    pub fn dec_selected(&mut self) {
-        if self.selected.is_none() {
-            if self.objects.len() > 0 {
-                self.selected = Some(0);
+    // objeto sob o mouse em uma vista; se as caixas de vários objetos
+    // contêm o ponto, fica o de menor caixa
+    fn pick(&self, response: &egui::Response, axes: [usize; 2]) -> Option<usize> {
+        let position = response.hover_pos()?;
+        let rect = response.rect;
+        let point = [
+            ((position.x - rect.min.x) / rect.width() * 2.0 - 1.0) as f64,
+            (1.0 - (position.y - rect.min.y) / rect.height() * 2.0) as f64,
+        ];
+
+        let mut picked: Option<(usize, f64)> = None;
+        for index in 0..self.text.len() {
+            let (min, max) = match self.text.object(index).scene_bounding_box() {
+                Some(bounding_box) => bounding_box,
+                None => continue,
+            };
+            let inside = (0..2).all(|i| min[axes[i]] <= point[i] && point[i] <= max[axes[i]]);
+            let area = (max[axes[0]] - min[axes[0]]) * (max[axes[1]] - min[axes[1]]);
+            if inside && picked.is_none_or(|(_, smallest)| area < smallest) {
+                picked = Some((index, area));
             }
-        } else {
-            let selected = self.selected.unwrap();
-            if selected > 0 {
-                self.selected = Some(selected - 1);
+        }
+        picked.map(|(index, _)| index)
+    }
+
+    // move o cursor e seleciona a letra imediatamente antes dele; com
+    // `extend`, as letras por onde o cursor passou continuam selecionadas
+    fn set_caret(&mut self, caret: usize, extend: bool) {
+        let caret = caret.min(self.text.len());
+        if extend {
+            for index in self.caret.min(caret)..self.caret.max(caret) {
+                self.selection.insert(self.text.object(index).id());
             }
+        } else {
+            self.selection.clear();
         }
 
+        self.caret = caret;
+        self.selected = self.caret.checked_sub(1);
+        if let Some(selected) = self.selected {
+            self.selection.insert(self.text.object(selected).id());
+        }
         self.update();
     }
 
+    pub fn caret_left(&mut self, extend: bool) {
+        self.set_caret(self.caret.saturating_sub(1), extend);
+    }
+
+    pub fn caret_right(&mut self, extend: bool) {
+        self.set_caret(self.caret + 1, extend);
+    }
+
+    pub fn caret_home(&mut self, extend: bool) {
+        self.set_caret(self.text.line_start(self.caret), extend);
+    }
+
+    pub fn caret_end(&mut self, extend: bool) {
+        self.set_caret(self.text.line_end(self.caret), extend);
+    }
+
     pub fn del_object(&mut self, index: usize) {
-        if self.objects.len() > index {
-            self.objects.remove(index);
-            if let Some(selected) = self.selected {
-                if self.objects.len() == 0 {
-                    self.selected = None;
-                } else if self.objects.len() == selected {
-                    self.selected = Some(selected - 1);
-                }
+        if self.text.len() > index {
+            self.text.remove(index, &self.glyph_registry);
+            if index < self.caret {
+                self.set_caret(self.caret - 1, false);
+            } else {
+                self.set_caret(self.caret, false);
             }
-            self.update();
         }
     }
 
-    pub fn add_object(&mut self, faces: &[FaceType]) {
-        let mut object = Object::new([
-            self.objects.len() as f64,
-            0.0,
-            0.0,
-            1.0,
-        ]);
-
-        for face in faces {
-            object.add_face(*face);
+    // insere no cursor; as letras seguintes são reposicionadas pelo texto
+    pub fn add_object(&mut self, symbol: char) {
+        if self.text.insert(self.caret, symbol, &mut self.glyph_registry) {
+            self.set_caret(self.caret + 1, false);
         }
-
-        self.objects.push(object);
-
-        self.update();
     }
 
     fn update(&mut self) {
         match self.selected {
             Some(selected) => {
-                self.extrusion = self.objects[selected as usize].extrusion.is_some();
+                self.extrusion = self.text.object(selected).extrusion.is_some();
                 if self.extrusion {
                     self.extrusion_string = format!(
                         "{}",
-                        self.objects[selected as usize].extrusion.unwrap(),
+                        self.text.object(selected).extrusion.unwrap(),
                     );
                 } else {
                     self.extrusion_string = String::new();
                 }
 
+                let bevel = self.text.object(selected).bevel;
+                self.bevel = bevel.is_some();
+                let bevel = bevel.unwrap_or(DEFAULT_BEVEL);
+                self.bevel_depth = bevel.depth;
+                self.bevel_width = bevel.width;
+                self.bevel_segments = bevel.segments;
+                self.bevel_profile = bevel.profile;
+
                 self.x_position_string = format!(
                     "{}",
-                    self.objects[selected].position[0],
+                    self.text.object(selected).position[0],
                 );
                 self.y_position_string = format!(
                     "{}",
-                    self.objects[selected].position[1],
+                    self.text.object(selected).position[1],
                 );
                 self.z_position_string = format!(
                     "{}",
-                    self.objects[selected].position[2],
+                    self.text.object(selected).position[2],
                 );
 
                 self.x_scale_string = format!(
                     "{}",
-                    self.objects[selected].scale[0],
+                    self.text.object(selected).scale[0],
                 );
                 self.y_scale_string = format!(
                     "{}",
-                    self.objects[selected].scale[1],
+                    self.text.object(selected).scale[1],
                 );
                 self.z_scale_string = format!(
                     "{}",
-                    self.objects[selected].scale[2],
+                    self.text.object(selected).scale[2],
                 );
 
-                self.x_rotation = self.objects[selected].rotation[0];
-                self.y_rotation = self.objects[selected].rotation[1];
-                self.z_rotation = self.objects[selected].rotation[2];
+                // os ângulos digitados só são trocados se não descreverem mais a
+                // rotação do objeto (evita saltos entre ângulos equivalentes)
+                let rotation = self.text.object(selected).rotation;
+                let angles = [self.x_rotation, self.y_rotation, self.z_rotation];
+                if from_euler(angles, self.rotation_order).angle_to(&rotation) > 1e-9 {
+                    [self.x_rotation, self.y_rotation, self.z_rotation] = to_euler(&rotation, self.rotation_order);
+                }
+
+                let (axis, angle) = to_axis_angle(&rotation);
+                self.x_axis_string = format!("{}", axis[0]);
+                self.y_axis_string = format!("{}", axis[1]);
+                self.z_axis_string = format!("{}", axis[2]);
+                self.rotation_angle = angle;
+
+                self.pivot = self.text.object(selected).pivot;
+                let pivot = self.text.object(selected).pivot_point();
+                self.x_pivot_string = format!("{}", pivot[0]);
+                self.y_pivot_string = format!("{}", pivot[1]);
+                self.z_pivot_string = format!("{}", pivot[2]);
+                self.scale_space = self.text.object(selected).scale_space;
+
+                self.tolerance = self.text.object(selected).tolerance;
+                self.weld_distance = self.text.object(selected).weld_distance;
+
+                self.shading = self.text.object(selected).shading;
+                self.crease_angle = self.text.object(selected).crease_angle;
             }
             None => {
                 self.extrusion_string = String::new();
 
+                self.bevel = false;
+                self.bevel_depth = DEFAULT_BEVEL.depth;
+                self.bevel_width = DEFAULT_BEVEL.width;
+                self.bevel_segments = DEFAULT_BEVEL.segments;
+                self.bevel_profile = DEFAULT_BEVEL.profile;
+
                 self.x_position_string = String::new();
                 self.y_position_string = String::new();
                 self.z_position_string = String::new();
@@ -683,55 +1284,139 @@ impl Application {
                 self.x_rotation = 0.0;
                 self.y_rotation = 0.0;
                 self.z_rotation = 0.0;
+
+                self.x_axis_string = String::new();
+                self.y_axis_string = String::new();
+                self.z_axis_string = String::new();
+                self.rotation_angle = 0.0;
+
+                self.pivot = Pivot::Origin;
+                self.x_pivot_string = String::new();
+                self.y_pivot_string = String::new();
+                self.z_pivot_string = String::new();
+                self.scale_space = ScaleSpace::Rotated;
+
+                self.tolerance = DEFAULT_TOLERANCE;
+                self.weld_distance = DEFAULT_WELD_DISTANCE;
+
+                self.shading = Shading::Smooth;
+                self.crease_angle = DEFAULT_CREASE_ANGLE;
             }
         }
 
-        let mut vertices: Vec<ShaderVertex> = Vec::new();
-        let mut lines_indices: Vec<u32> = Vec::new();
+        self.update_scene();
+    }
 
-        let mut count: usize = 0;
+    // só os objetos novos ou alterados são reescritos na GPU: mover, girar ou
+    // mudar a escala de um objeto troca apenas a sua instância, a seleção e o
+    // mouse trocam só o estado das instâncias, e glifos iguais compartilham a
+    // mesma malha
+    fn update_scene(&mut self) {
+        let ids: HashSet<usize> = (0..self.text.len())
+            .map(|index| self.text.object(index).id())
+            .collect();
+        self.selection.retain(|id| ids.contains(id));
+
+        let highlight = match self.highlight {
+            Highlight::Recolor => INSTANCE_HIGHLIGHT,
+            Highlight::Outline => 0,
+        };
 
-        for object in self.objects.iter() {
-            let vertices_len = vertices.len();
+        let mut states: HashMap<usize, u32> = HashMap::new();
+        let mut order: Vec<usize> = Vec::with_capacity(self.text.len() + 1);
 
-            let object_vertices = object.get_vertices_vec();
-            let object_lines_indices_vec = object.get_lines_indices_vec();
+        for index in 0..self.text.len() {
+            let changes: Changes = self.text.object_mut(index).take_changes();
+            let object = self.text.object(index);
+            let id = object.id();
+            let new = !self.scene.contains(id);
 
-            for vertex in object_vertices {
-                if let Some(selected) = self.selected {
-                    if count == selected {
-                        vertices.push(ShaderVertex {
-                            position: vertex.position,
-                            color: [1.0, 0.0, 1.0],
-                        });
-                    } else {
-                        vertices.push(vertex);
-                    }
-                } else {
-                    vertices.push(vertex);
-                }
+            let mut state = 0;
+            if self.selection.contains(&id) {
+                state |= INSTANCE_SELECTED;
+            }
+            if self.hovered == Some(id) {
+                state |= INSTANCE_HOVERED;
+            }
+            let restated = self.scene_states.get(&id).copied().unwrap_or(0) != state | highlight;
+
+            if new || changes.geometry {
+                self.scene.set_mesh(
+                    &self.device,
+                    &self.queue,
+                    id,
+                    &object.get_vertices_vec(),
+                    &object.get_lines_indices_vec(),
+                    &object.get_triangles_indices_vec(),
+                );
             }
 
-            for index in object_lines_indices_vec {
-                lines_indices.push(index + (vertices_len as u32));
+            if new || changes.transform || restated {
+                let (model, normal) = object.model_matrix();
+                self.scene.set_instance(id, ShaderInstance {
+                    model,
+                    color: [1.0, 1.0, 1.0, 1.0],
+                    state: state | highlight,
+                    normal,
+                });
             }
 
-            count += 1;
+            states.insert(id, state | highlight);
+            order.push(id);
+
+            // contorno: caixa em volta do objeto, sempre destacada pela cor
+            if self.highlight == Highlight::Outline && state != 0 {
+                let matrix = match object.bounding_box_matrix(OUTLINE_MARGIN) {
+                    Some(matrix) => matrix,
+                    None => continue,
+                };
+                let outline_id = OUTLINE_IDS + id;
+                let new = !self.scene.contains(outline_id);
+                if new {
+                    let (vertices, indices) = box_mesh();
+                    self.scene.set_mesh(&self.device, &self.queue, outline_id, &vertices, &indices, &[]);
+                }
+                if new || changes.transform || changes.geometry || restated {
+                    self.scene.set_instance(outline_id, ShaderInstance {
+                        model: matrix,
+                        color: [1.0, 1.0, 1.0, 1.0],
+                        state: state | INSTANCE_HIGHLIGHT,
+                        normal: IDENTITY,
+                    });
+                }
+                order.push(outline_id);
+            }
         }
 
-        self.vertices_buffer = self.device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-            label: Some("vertex-buffer"),
-            contents: bytemuck::cast_slice(&vertices),
-            usage: wgpu::BufferUsages::VERTEX,
+        // cursor: segmento vertical da linha de base até a altura das letras,
+        // levado até a posição do cursor pela matriz de modelo
+        if !self.scene.contains(CARET_ID) {
+            let vertices: Vec<ShaderVertex> = [-0.5, 0.5]
+                .iter()
+                .map(|&y| ShaderVertex {
+                    position: [0.0, y, 0.0],
+                    color: [1.0, 1.0, 0.0],
+                    normal: [0.0, 0.0, 0.0],
+                })
+                .collect();
+            self.scene.set_mesh(&self.device, &self.queue, CARET_ID, &vertices, &[0, 1], &[]);
+        }
+        let caret = self.text.caret_position(self.caret);
+        self.scene.set_instance(CARET_ID, ShaderInstance {
+            model: [
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+                [caret[0] as f32, caret[1] as f32, 0.0, 1.0],
+            ],
+            color: [1.0, 1.0, 1.0, 1.0],
+            state: 0,
+            normal: IDENTITY,
         });
+        order.push(CARET_ID);
 
-        self.lines_indices_len = lines_indices.len() as u32;
-
-        self.lines_indices_buffer = self.device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-            label: Some("line-indices-buffer"),
-            contents: bytemuck::cast_slice(&lines_indices),
-            usage: wgpu::BufferUsages::INDEX,
-        });
+        self.scene.update_order(&self.device, &self.queue, &order);
+        self.scene_states = states;
     }
 
     pub fn render(&mut self, scale_factor: f32) -> Result<(), wgpu::SurfaceError> {
@@ -770,36 +1455,32 @@ impl Application {
                     encoder.push_debug_group("orthographic-view-front-render");
                     self.front_view.render(
                         &mut encoder,
-                        self.vertices_buffer.slice(..),
-                        self.lines_indices_buffer.slice(..),
-                        self.lines_indices_len as u32,
+                        &self.scene,
+                        self.render_mode,
                     );
                     encoder.pop_debug_group();
 
                     encoder.push_debug_group("orthographic-view-side-render");
                     self.side_view.render(
                         &mut encoder,
-                        self.vertices_buffer.slice(..),
-                        self.lines_indices_buffer.slice(..),
-                        self.lines_indices_len as u32,
+                        &self.scene,
+                        self.render_mode,
                     );
                     encoder.pop_debug_group();
 
                     encoder.push_debug_group("orthographic-view-top-render");
                     self.top_view.render(
                         &mut encoder,
-                        self.vertices_buffer.slice(..),
-                        self.lines_indices_buffer.slice(..),
-                        self.lines_indices_len as u32,
+                        &self.scene,
+                        self.render_mode,
                     );
                     encoder.pop_debug_group();
 
                     encoder.push_debug_group("orthographic-view-perspective-render");
                     self.perspective_view.render(
                         &mut encoder,
-                        self.vertices_buffer.slice(..),
-                        self.lines_indices_buffer.slice(..),
-                        self.lines_indices_len as u32,
+                        &self.scene,
+                        self.render_mode,
                     );
                     encoder.pop_debug_group();
                 }
@@ -807,9 +1488,8 @@ impl Application {
                     encoder.push_debug_group("orthographic-view-front-render");
                     self.front_view.render(
                         &mut encoder,
-                        self.vertices_buffer.slice(..),
-                        self.lines_indices_buffer.slice(..),
-                        self.lines_indices_len as u32,
+                        &self.scene,
+                        self.render_mode,
                     );
                     encoder.pop_debug_group();
                 }
@@ -817,9 +1497,8 @@ impl Application {
                     encoder.push_debug_group("orthographic-view-side-render");
                     self.side_view.render(
                         &mut encoder,
-                        self.vertices_buffer.slice(..),
-                        self.lines_indices_buffer.slice(..),
-                        self.lines_indices_len as u32,
+                        &self.scene,
+                        self.render_mode,
                     );
                     encoder.pop_debug_group();
                 }
@@ -827,9 +1506,8 @@ impl Application {
                     encoder.push_debug_group("orthographic-view-top-render");
                     self.top_view.render(
                         &mut encoder,
-                        self.vertices_buffer.slice(..),
-                        self.lines_indices_buffer.slice(..),
-                        self.lines_indices_len as u32,
+                        &self.scene,
+                        self.render_mode,
                     );
                     encoder.pop_debug_group();
                 }
@@ -837,9 +1515,8 @@ impl Application {
                     encoder.push_debug_group("orthographic-view-perspective-render");
                     self.perspective_view.render(
                         &mut encoder,
-                        self.vertices_buffer.slice(..),
-                        self.lines_indices_buffer.slice(..),
-                        self.lines_indices_len as u32,
+                        &self.scene,
+                        self.render_mode,
                     );
                     encoder.pop_debug_group();
                 }
@@ -890,3 +1567,30 @@ impl Application {
         Ok(())
     }
 }
+
+// arestas do cubo de 0 a 1, usado pelos contornos de destaque
+fn box_mesh() -> (Vec<ShaderVertex>, Vec<u32>) {
+    let vertices = (0..8)
+        .map(|corner| ShaderVertex {
+            position: [
+                (corner & 1) as f32,
+                ((corner >> 1) & 1) as f32,
+                ((corner >> 2) & 1) as f32,
+            ],
+            color: [1.0, 1.0, 1.0],
+            normal: [0.0, 0.0, 0.0],
+        })
+        .collect();
+
+    // cada aresta liga dois cantos que diferem em um único eixo
+    let mut indices = Vec::with_capacity(24);
+    for corner in 0..8u32 {
+        for axis in [1, 2, 4] {
+            if corner & axis == 0 {
+                indices.extend([corner, corner | axis]);
+            }
+        }
+    }
+
+    (vertices, indices)
+}
@@ -1,9 +1,39 @@
 use std::mem::size_of;
 
-use crate::types::ShaderVertex;
+use crate::types::{
+    ShaderInstance,
+    ShaderVertex,
+};
+
+// distância máxima entre uma curva e a poligonal que a aproxima
+pub const DEFAULT_TOLERANCE: f64 = 0.005;
+
+// distância abaixo da qual dois vértices da malha são fundidos
+pub const DEFAULT_WELD_DISTANCE: f64 = 1e-6;
+
+// ângulo entre faces acima do qual o sombreamento suave mantém a aresta viva
+pub const DEFAULT_CREASE_ANGLE: f64 = 30.0;
+
+// espaço livre à esquerda e à direita dos glifos internos
+pub const SIDE_BEARING: f64 = 0.1;
+
+// formato das texturas de profundidade das vistas
+pub const DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth32Float;
+
+// bits do estado de cada instância, lidos pelos shaders
+pub const INSTANCE_SELECTED: u32 = 1;
+pub const INSTANCE_HOVERED: u32 = 2;
+// destaca o estado mudando a cor dos vértices
+pub const INSTANCE_HIGHLIGHT: u32 = 4;
 
 pub const VERTICES_BUFFER_LAYOUT: wgpu::VertexBufferLayout = wgpu::VertexBufferLayout {
     array_stride: size_of::<ShaderVertex>() as wgpu::BufferAddress,
     step_mode: wgpu::VertexStepMode::Vertex,
-    attributes: &wgpu::vertex_attr_array![0 => Float32x3, 1 => Float32x3],
+    attributes: &wgpu::vertex_attr_array![0 => Float32x3, 1 => Float32x3, 2 => Float32x3],
+};
+
+pub const INSTANCES_BUFFER_LAYOUT: wgpu::VertexBufferLayout = wgpu::VertexBufferLayout {
+    array_stride: size_of::<ShaderInstance>() as wgpu::BufferAddress,
+    step_mode: wgpu::VertexStepMode::Instance,
+    attributes: &wgpu::vertex_attr_array![3 => Float32x4, 4 => Float32x4, 5 => Float32x4, 6 => Float32x4, 7 => Float32x4, 8 => Uint32, 9 => Float32x3, 10 => Float32x3, 11 => Float32x3],
 };
\ No newline at end of file
@@ -0,0 +1,254 @@
+use std::{
+    fmt,
+    fs,
+    io,
+    path::Path,
+};
+
+use owned_ttf_parser::{
+    AsFaceRef,
+    OwnedFace,
+};
+use ttf_parser::{
+    gpos::{
+        PairAdjustment,
+        PositioningSubtable,
+    },
+    Face,
+    FaceParsingError,
+    GlyphId,
+    OutlineBuilder,
+    Tag,
+};
+
+use crate::{
+    glyph_registry::Glyph,
+    types::{
+        Point,
+        Segment,
+    },
+};
+
+#[derive(Debug)]
+pub enum FontError {
+    Io(io::Error),
+    Parse(FaceParsingError),
+}
+
+impl fmt::Display for FontError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FontError::Io(error) => write!(f, "Não foi possível ler o arquivo: {}", error),
+            FontError::Parse(error) => write!(f, "Arquivo de fonte inválido: {}", error),
+        }
+    }
+}
+
+// converte os contornos da fonte (pontos dentro e fora da curva já
+// resolvidos em trechos retos, quadráticos e cúbicos) para o espaço do glifo
+struct ContourBuilder {
+    contours: Vec<Vec<Segment>>,
+    contour: Vec<Segment>,
+    start: Point,
+    current: Point,
+    scale: f64,
+    offset: Point,
+}
+
+impl ContourBuilder {
+    fn point(&self, x: f32, y: f32) -> Point {
+        [
+            x as f64 * self.scale + self.offset[0],
+            y as f64 * self.scale + self.offset[1],
+        ]
+    }
+}
+
+impl OutlineBuilder for ContourBuilder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.close();
+        self.start = self.point(x, y);
+        self.current = self.start;
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let end = self.point(x, y);
+        // descarta trechos de comprimento nulo
+        if end != self.current {
+            self.contour.push(Segment::Line(end));
+            self.current = end;
+        }
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let end = self.point(x, y);
+        self.contour.push(Segment::Quadratic(self.point(x1, y1), end));
+        self.current = end;
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let end = self.point(x, y);
+        self.contour.push(Segment::Cubic(self.point(x1, y1), self.point(x2, y2), end));
+        self.current = end;
+    }
+
+    fn close(&mut self) {
+        if self.contour.is_empty() {
+            return;
+        }
+        // o contorno precisa terminar no ponto em que começou
+        if self.current != self.start {
+            self.contour.push(Segment::Line(self.start));
+            self.current = self.start;
+        }
+        self.contours.push(std::mem::take(&mut self.contour));
+    }
+}
+
+pub struct Font {
+    pub name: String,
+    // a fonte é lida uma única vez, ao ser carregada
+    face: OwnedFace,
+}
+
+impl Font {
+    pub fn load(path: &Path) -> Result<Self, FontError> {
+        let data = fs::read(path).map_err(FontError::Io)?;
+
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.display().to_string());
+
+        Self::from_data(name, data)
+    }
+
+    fn from_data(name: String, data: Vec<u8>) -> Result<Self, FontError> {
+        let face = OwnedFace::from_vec(data, 0).map_err(FontError::Parse)?;
+
+        Ok(Self {
+            name,
+            face,
+        })
+    }
+
+    fn face(&self) -> &Face<'_> {
+        self.face.as_face_ref()
+    }
+
+    // escala que faz a altura das maiúsculas ocupar uma unidade
+    fn scale(face: &Face) -> f64 {
+        let height = face
+            .capital_height()
+            .filter(|height| *height > 0)
+            .unwrap_or_else(|| face.ascender().max(1));
+        1.0 / height as f64
+    }
+
+    // glifo do caractere, com a linha de base em y = -0.5 e o avanço
+    // centralizado em x = 0
+    pub fn glyph(&self, symbol: char) -> Option<Glyph> {
+        let face = self.face();
+        let glyph_id = face.glyph_index(symbol)?;
+
+        let scale = Self::scale(face);
+        let advance = face.glyph_hor_advance(glyph_id).unwrap_or(0) as f64 * scale;
+        let left_side_bearing = face.glyph_hor_side_bearing(glyph_id).unwrap_or(0) as f64 * scale;
+
+        let mut builder = ContourBuilder {
+            contours: Vec::new(),
+            contour: Vec::new(),
+            start: [0.0, 0.0],
+            current: [0.0, 0.0],
+            scale,
+            offset: [-advance / 2.0, -0.5],
+        };
+
+        face.outline_glyph(glyph_id, &mut builder);
+        builder.close();
+
+        Some(Glyph::with_metrics(&builder.contours, advance, left_side_bearing))
+    }
+
+    // ajuste horizontal entre dois caracteres segundo a tabela kern ou, nas
+    // fontes OpenType que não a têm, os pares da feature kern da tabela GPOS
+    pub fn kerning(&self, left: char, right: char) -> f64 {
+        let face = self.face();
+        let (left, right) = match (face.glyph_index(left), face.glyph_index(right)) {
+            (Some(left), Some(right)) => (left, right),
+            _ => return 0.0,
+        };
+
+        let kerning: i32 = match face.tables().kern {
+            Some(table) => table.subtables
+                .into_iter()
+                .filter(|subtable| subtable.horizontal && !subtable.variable && !subtable.has_cross_stream)
+                .filter_map(|subtable| subtable.glyphs_kerning(left, right))
+                .map(i32::from)
+                .sum(),
+            None => Self::pair_adjustment(face, left, right),
+        };
+
+        kerning as f64 * Self::scale(face)
+    }
+
+    // soma o avanço do primeiro glifo de cada lookup da feature kern; só os
+    // ajustes de par são lidos, sem as variações de fontes variáveis
+    fn pair_adjustment(face: &Face, left: GlyphId, right: GlyphId) -> i32 {
+        let table = match face.tables().gpos {
+            Some(table) => table,
+            None => return 0,
+        };
+
+        let kern = Tag::from_bytes(b"kern");
+        let mut lookups: Vec<u16> = table.features
+            .into_iter()
+            .filter(|feature| feature.tag == kern)
+            .flat_map(|feature| feature.lookup_indices)
+            .collect();
+        lookups.sort_unstable();
+        lookups.dedup();
+
+        lookups
+            .into_iter()
+            .filter_map(|index| table.lookups.get(index))
+            .filter_map(|lookup| {
+                // em cada lookup vale a primeira subtabela que cobre o par
+                lookup.subtables
+                    .into_iter::<PositioningSubtable>()
+                    .find_map(|subtable| match subtable {
+                        PositioningSubtable::Pair(PairAdjustment::Format1 { coverage, sets }) => {
+                            sets.get(coverage.get(left)?)?.get(right)
+                        }
+                        PositioningSubtable::Pair(PairAdjustment::Format2 { coverage, classes, matrix }) => {
+                            coverage.get(left)?;
+                            matrix.get((classes.0.get(left), classes.1.get(right)))
+                        }
+                        _ => None,
+                    })
+            })
+            .map(|(first, _)| i32::from(first.x_advance))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a Ubuntu Light que acompanha o egui só tem kerning na tabela GPOS
+    fn ubuntu_light() -> Font {
+        let data = egui::FontDefinitions::default().font_data["Ubuntu-Light"].to_vec();
+        Font::from_data("Ubuntu-Light".to_string(), data).unwrap()
+    }
+
+    #[test]
+    fn gpos_kerning_pulls_pairs_together() {
+        let font = ubuntu_light();
+        assert!(font.face().tables().kern.is_none());
+
+        assert!(font.kerning('A', 'V') < 0.0);
+        assert!(font.kerning('T', 'o') < 0.0);
+        assert_eq!(font.kerning('A', 'B'), 0.0);
+    }
+}
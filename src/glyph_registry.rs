@@ -0,0 +1,358 @@
+use std::{
+    collections::HashMap,
+    path::Path,
+};
+
+use crate::{
+    constants::SIDE_BEARING,
+    font::{
+        Font,
+        FontError,
+    },
+    symbols::*,
+    types::{
+        Point,
+        Segment,
+    },
+};
+
+// pontos de encaixe dos acentos (acima ou abaixo do glifo)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Anchor {
+    Top,
+    Bottom,
+}
+
+#[derive(Debug, Clone)]
+pub struct Glyph {
+    pub contours: Vec<Vec<Segment>>,
+    // caixa delimitadora no plano xy (inclui pontos de controle)
+    pub min: [f64; 2],
+    pub max: [f64; 2],
+    // distância entre a origem deste glifo e a do próximo
+    pub advance: f64,
+    // distância entre a origem e o início da caixa delimitadora
+    pub left_side_bearing: f64,
+    pub anchors: Vec<(Anchor, Point)>,
+}
+
+impl Glyph {
+    // glifo interno, com o mesmo espaço livre dos dois lados
+    pub fn new<C: AsRef<[Segment]>>(contours: &[C]) -> Self {
+        let mut glyph = Self::with_metrics(contours, 0.0, SIDE_BEARING);
+        glyph.advance = glyph.max[0] - glyph.min[0] + 2.0 * SIDE_BEARING;
+        glyph
+    }
+
+    pub fn with_metrics<C: AsRef<[Segment]>>(
+        contours: &[C],
+        advance: f64,
+        left_side_bearing: f64,
+    ) -> Self {
+        let mut min = [f64::MAX, f64::MAX];
+        let mut max = [f64::MIN, f64::MIN];
+
+        for segment in contours.iter().flat_map(|contour| contour.as_ref().iter()) {
+            let points = match *segment {
+                Segment::Line(end) => vec![end],
+                Segment::Quadratic(control, end) => vec![control, end],
+                Segment::Cubic(first_control, second_control, end) => {
+                    vec![first_control, second_control, end]
+                }
+            };
+
+            for point in points {
+                min[0] = min[0].min(point[0]);
+                min[1] = min[1].min(point[1]);
+                max[0] = max[0].max(point[0]);
+                max[1] = max[1].max(point[1]);
+            }
+        }
+
+        if contours.iter().all(|contour| contour.as_ref().is_empty()) {
+            min = [0.0, 0.0];
+            max = [0.0, 0.0];
+        }
+
+        // por padrão, os acentos se encaixam centralizados no topo e na base
+        let center = (min[0] + max[0]) / 2.0;
+
+        Self {
+            contours: contours.iter().map(|contour| contour.as_ref().to_vec()).collect(),
+            min,
+            max,
+            advance,
+            left_side_bearing,
+            anchors: vec![
+                (Anchor::Top, [center, max[1]]),
+                (Anchor::Bottom, [center, min[1]]),
+            ],
+        }
+    }
+
+    // acento sem avanço próprio, encaixado pela âncora na origem
+    pub fn mark<C: AsRef<[Segment]>>(contours: &[C], anchor: Anchor) -> Self {
+        let mut glyph = Self::with_metrics(contours, 0.0, 0.0);
+        glyph.anchors = vec![(anchor, [0.0, 0.0])];
+        glyph
+    }
+
+    fn anchor(&self, anchor: Anchor) -> Option<Point> {
+        self.anchors
+            .iter()
+            .find(|(kind, _)| *kind == anchor)
+            .map(|(_, point)| *point)
+    }
+
+    // glifo composto pela base e pelos acentos, cada acento deslocado para
+    // que sua âncora coincida com a âncora de mesmo tipo da base; mantém o
+    // avanço e o posicionamento horizontal da base
+    pub fn compose(base: &Glyph, marks: &[&Glyph]) -> Self {
+        let mut contours = base.contours.clone();
+
+        for mark in marks {
+            let offset = mark.anchors.iter().find_map(|&(kind, point)| {
+                base.anchor(kind).map(|target| [target[0] - point[0], target[1] - point[1]])
+            });
+            let offset = match offset {
+                Some(offset) => offset,
+                None => continue,
+            };
+
+            let translate = |point: Point| [point[0] + offset[0], point[1] + offset[1]];
+            contours.extend(mark.contours.iter().map(|contour| {
+                contour
+                    .iter()
+                    .map(|segment| match *segment {
+                        Segment::Line(end) => Segment::Line(translate(end)),
+                        Segment::Quadratic(control, end) => {
+                            Segment::Quadratic(translate(control), translate(end))
+                        }
+                        Segment::Cubic(first_control, second_control, end) => Segment::Cubic(
+                            translate(first_control),
+                            translate(second_control),
+                            translate(end),
+                        ),
+                    })
+                    .collect::<Vec<Segment>>()
+            }));
+        }
+
+        let mut glyph = Self::with_metrics(
+            &contours,
+            base.advance,
+            base.left_side_bearing,
+        );
+        glyph.left_side_bearing -= base.min[0] - glyph.min[0];
+        glyph.anchors = base.anchors.clone();
+        glyph
+    }
+}
+
+#[derive(Default)]
+pub struct GlyphRegistry {
+    glyphs: HashMap<char, Glyph>,
+    // fontes carregadas e a selecionada (None usa os glifos internos)
+    fonts: Vec<Font>,
+    font: Option<usize>,
+    font_glyphs: HashMap<(usize, char), Glyph>,
+    // ajustes entre pares de glifos internos
+    kerning: HashMap<(char, char), f64>,
+    // base de cada glifo composto, usada também para o ajuste entre pares
+    bases: HashMap<char, char>,
+}
+
+impl GlyphRegistry {
+    pub fn new() -> Self {
+        let mut registry = Self::default();
+
+        registry.register('A', Glyph::new(&a::SA));
+        registry.register('B', Glyph::new(&b::SB));
+        registry.register('C', Glyph::new(&c::SC));
+        registry.register('D', Glyph::new(&d::SD));
+        registry.register('E', Glyph::new(&e::SE));
+        registry.register('F', Glyph::new(&f::SF));
+        registry.register('G', Glyph::new(&g::SG));
+        registry.register('H', Glyph::new(&h::SH));
+        registry.register('I', Glyph::new(&i::SI));
+        registry.register('J', Glyph::new(&j::SJ));
+        registry.register('K', Glyph::new(&k::SK));
+        registry.register('L', Glyph::new(&l::SL));
+        registry.register('M', Glyph::new(&m::SM));
+        registry.register('N', Glyph::new(&n::SN));
+        registry.register('O', Glyph::new(&o::SO));
+        registry.register('P', Glyph::new(&p::SP));
+        registry.register('Q', Glyph::new(&q::SQ));
+        registry.register('R', Glyph::new(&r::SR));
+        registry.register('S', Glyph::new(&s::SS));
+        registry.register('T', Glyph::new(&t::ST));
+        registry.register('U', Glyph::new(&u::SU));
+        registry.register('V', Glyph::new(&v::SV));
+        registry.register('W', Glyph::new(&w::SW));
+        registry.register('X', Glyph::new(&x::SX));
+        registry.register('Y', Glyph::new(&y::SY));
+        registry.register('Z', Glyph::new(&z::SZ));
+        registry.register('0', Glyph::new(&n0::S0));
+        registry.register('1', Glyph::new(&n1::S1));
+        registry.register('2', Glyph::new(&n2::S2));
+        registry.register('3', Glyph::new(&n3::S3));
+        registry.register('4', Glyph::new(&n4::S4));
+        registry.register('5', Glyph::new(&n5::S5));
+        registry.register('6', Glyph::new(&n6::S6));
+        registry.register('7', Glyph::new(&n7::S7));
+        registry.register('8', Glyph::new(&n8::S8));
+        registry.register('9', Glyph::new(&n9::S9));
+        // o espaço não tem contornos, só avanço
+        registry.register(' ', Glyph::with_metrics::<&[Segment]>(&[], 0.4, 0.0));
+
+        // acentos, registrados nos caracteres combinantes correspondentes
+        registry.register('\u{301}', Glyph::mark(&acute::SACUTE, Anchor::Top));
+        registry.register('\u{300}', Glyph::mark(&grave::SGRAVE, Anchor::Top));
+        registry.register('\u{302}', Glyph::mark(&circumflex::SCIRCUMFLEX, Anchor::Top));
+        registry.register('\u{303}', Glyph::mark(&tilde::STILDE, Anchor::Top));
+        registry.register('\u{327}', Glyph::mark(&cedilla::SCEDILLA, Anchor::Bottom));
+
+        for (symbol, base, mark) in [
+            ('Á', 'A', '\u{301}'),
+            ('À', 'A', '\u{300}'),
+            ('Â', 'A', '\u{302}'),
+            ('Ã', 'A', '\u{303}'),
+            ('É', 'E', '\u{301}'),
+            ('È', 'E', '\u{300}'),
+            ('Ê', 'E', '\u{302}'),
+            ('Í', 'I', '\u{301}'),
+            ('Ì', 'I', '\u{300}'),
+            ('Î', 'I', '\u{302}'),
+            ('Ó', 'O', '\u{301}'),
+            ('Ò', 'O', '\u{300}'),
+            ('Ô', 'O', '\u{302}'),
+            ('Õ', 'O', '\u{303}'),
+            ('Ú', 'U', '\u{301}'),
+            ('Ù', 'U', '\u{300}'),
+            ('Û', 'U', '\u{302}'),
+            ('Ñ', 'N', '\u{303}'),
+            ('Ç', 'C', '\u{327}'),
+        ] {
+            registry.register_composite(symbol, base, &[mark]);
+        }
+
+        for (left, right, kerning) in [
+            ('A', 'T', -0.1),
+            ('A', 'V', -0.1),
+            ('A', 'W', -0.1),
+            ('A', 'Y', -0.1),
+            ('F', 'A', -0.1),
+            ('L', 'T', -0.1),
+            ('L', 'V', -0.1),
+            ('L', 'W', -0.1),
+            ('L', 'Y', -0.1),
+            ('P', 'A', -0.1),
+            ('T', 'A', -0.1),
+            ('V', 'A', -0.1),
+            ('W', 'A', -0.1),
+            ('Y', 'A', -0.1),
+        ] {
+            registry.kerning.insert((left, right), kerning);
+        }
+
+        registry
+    }
+
+    // adiciona ou substitui o glifo associado ao caractere
+    pub fn register(&mut self, symbol: char, glyph: Glyph) {
+        self.glyphs.insert(symbol, glyph);
+    }
+
+    // registra o glifo composto pela base e pelos acentos já registrados
+    pub fn register_composite(&mut self, symbol: char, base: char, marks: &[char]) {
+        let base_glyph = match self.glyphs.get(&base) {
+            Some(glyph) => glyph,
+            None => return,
+        };
+        let marks: Vec<&Glyph> = marks
+            .iter()
+            .filter_map(|mark| self.glyphs.get(mark))
+            .collect();
+
+        let glyph = Glyph::compose(base_glyph, &marks);
+        self.register(symbol, glyph);
+        self.bases.insert(symbol, base);
+    }
+
+    pub fn load_font(&mut self, path: &Path) -> Result<usize, FontError> {
+        self.fonts.push(Font::load(path)?);
+        Ok(self.fonts.len() - 1)
+    }
+
+    pub fn font_names(&self) -> Vec<&str> {
+        self.fonts.iter().map(|font| font.name.as_str()).collect()
+    }
+
+    pub fn font(&self) -> Option<usize> {
+        self.font
+    }
+
+    pub fn select_font(&mut self, font: Option<usize>) {
+        self.font = font.filter(|&index| index < self.fonts.len());
+    }
+
+    // com uma fonte selecionada, usa qualquer caractere coberto por ela;
+    // caso contrário, usa os glifos registrados (minúsculas viram maiúsculas)
+    pub fn get(&mut self, symbol: char) -> Option<&Glyph> {
+        if let Some(font) = self.font {
+            if !self.font_glyphs.contains_key(&(font, symbol)) {
+                let glyph = self.fonts[font].glyph(symbol)?;
+                self.font_glyphs.insert((font, symbol), glyph);
+            }
+            return self.font_glyphs.get(&(font, symbol));
+        }
+
+        if self.glyphs.contains_key(&symbol) {
+            return self.glyphs.get(&symbol);
+        }
+
+        symbol
+            .to_uppercase()
+            .next()
+            .and_then(|upper| self.glyphs.get(&upper))
+    }
+
+    // ajuste somado ao avanço do glifo da esquerda quando seguido pelo da
+    // direita; só se aplica a glifos vindos da mesma fonte
+    pub fn kerning(&self, font: Option<usize>, left: char, right: char) -> f64 {
+        match font {
+            Some(font) => self.fonts[font].kerning(left, right),
+            None => {
+                let base = |symbol: char| {
+                    let upper = symbol.to_uppercase().next().unwrap_or(symbol);
+                    self.bases.get(&upper).copied().unwrap_or(upper)
+                };
+                self.kerning
+                    .get(&(base(left), base(right)))
+                    .copied()
+                    .unwrap_or(0.0)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object::Object;
+
+    #[test]
+    fn built_in_glyphs_are_valid() {
+        let registry = GlyphRegistry::new();
+        let mut symbols: Vec<&char> = registry.glyphs.keys().collect();
+        symbols.sort();
+
+        for symbol in symbols {
+            let mut object = Object::new([0.0, 0.0, 0.0, 1.0]);
+            object.set_outline(&registry.glyphs[symbol].contours);
+
+            let report = object.report();
+            assert!(report.is_valid(), "glifo {:?}: {:?}", symbol, report.problems);
+        }
+    }
+}
\ No newline at end of file
@@ -8,6 +8,7 @@ use winit::{
     event::{
         ElementState,
         Event,
+        ModifiersState,
         VirtualKeyCode,
         WindowEvent,
     },
@@ -23,23 +24,40 @@ use winit::{
 
 mod application;
 mod constants;
+mod font;
+mod glyph_registry;
+mod mesh;
 mod object;
 mod orthographic_view;
+mod outline;
 mod perspective_view;
+mod pipelines;
+mod rotation;
+mod scene;
 mod symbols;
+mod text;
 mod texture;
+mod triangulation;
 mod types;
+mod validation;
 
 use application::Application;
 
-use crate::symbols::*;
-
 async fn run(event_loop: EventLoop<()>, window: Rc<Window>) {
     let mut app = Application::new(&window).await;
+    let mut modifiers = ModifiersState::empty();
 
     event_loop.run(move |event, _, control_flow| {
         app.handle_event(&event);
 
+        // acompanhado mesmo quando a interface fica com o evento
+        if let Event::WindowEvent {
+            event: WindowEvent::ModifiersChanged(state),
+            ..
+        } = event {
+            modifiers = state;
+        }
+
         if app.captures_event(&event) {
             return;
         }
@@ -63,37 +81,41 @@ async fn run(event_loop: EventLoop<()>, window: Rc<Window>) {
             } => {
                 match input.virtual_keycode {
                     Some(VirtualKeyCode::Left) if input.state == ElementState::Pressed => {
-                        app.dec_selected();
+                        app.caret_left(modifiers.shift());
                     }
                     Some(VirtualKeyCode::Right) if input.state == ElementState::Pressed => {
-                        app.inc_selected();
-                    }
-                    Some(VirtualKeyCode::Delete) if input.state == ElementState::Pressed => {
-                        if let Some(selected) = app.selected {
-                            app.del_object(selected + 1);
-                        }
-                    }
-                    Some(VirtualKeyCode::Back) if input.state == ElementState::Pressed => {
-                        if let Some(selected) = app.selected {
-                            app.del_object(selected);
-                        }
+                        app.caret_right(modifiers.shift());
                     }
-                    Some(VirtualKeyCode::I) if input.state == ElementState::Pressed => {
-                        app.add_object(&i::SI);
+                    Some(VirtualKeyCode::Home) if input.state == ElementState::Pressed => {
+                        app.caret_home(modifiers.shift());
                     }
-                    Some(VirtualKeyCode::L) if input.state == ElementState::Pressed => {
-                        app.add_object(&l::SL);
+                    Some(VirtualKeyCode::End) if input.state == ElementState::Pressed => {
+                        app.caret_end(modifiers.shift());
                     }
-                    Some(VirtualKeyCode::U) if input.state == ElementState::Pressed => {
-                        app.add_object(&u::SU);
+                    Some(VirtualKeyCode::Delete) if input.state == ElementState::Pressed => {
+                        app.del_object(app.caret);
                     }
-                    Some(VirtualKeyCode::Z) if input.state == ElementState::Pressed => {
-                        app.add_object(&z::SZ);
+                    Some(VirtualKeyCode::Back) if input.state == ElementState::Pressed && app.caret > 0 => {
+                        app.del_object(app.caret - 1);
                     }
                     _ => {}
                 };
             }
 
+            Event::WindowEvent {
+                event: WindowEvent::ReceivedCharacter('\r' | '\n'),
+                ..
+            } => {
+                app.add_object('\n');
+            }
+
+            Event::WindowEvent {
+                event: WindowEvent::ReceivedCharacter(symbol),
+                ..
+            } if !symbol.is_control() => {
+                app.add_object(symbol);
+            }
+
             Event::RedrawRequested(_) => {
                 if let Err(e) = app.render(window.scale_factor() as f32) {
                     if e == SurfaceError::Outdated {
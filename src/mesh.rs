@@ -0,0 +1,541 @@
+use std::collections::{
+    HashMap,
+    VecDeque,
+};
+
+use crate::types::{
+    FaceType,
+    Point,
+    Position,
+};
+
+// limite do alongamento da bissetriz em cantos muito agudos
+const MAX_MITER: f64 = 2.0;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Vertex {
+    pub position: Position,
+    // uma das semiarestas que partem deste vértice
+    pub half_edge: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Edge {
+    pub vertices: [usize; 2],
+    // semiarestas das faces que usam a aresta, na ordem em que as faces
+    // foram adicionadas: 1 na borda, 2 no interior e mais de 2 quando a
+    // malha não é uma variedade
+    pub half_edges: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct HalfEdge {
+    pub origin: usize,
+    // semiaresta oposta, da face vizinha (None na borda da malha)
+    pub twin: Option<usize>,
+    // próxima semiaresta da mesma face
+    pub next: usize,
+    pub face: usize,
+    pub edge: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Face {
+    pub half_edge: usize,
+}
+
+// malha de semiarestas indexada; vértices iguais e arestas repetidas são
+// encontrados por tabelas de dispersão em vez de buscas lineares
+#[derive(Debug, Default)]
+pub struct Mesh {
+    vertices: Vec<Vertex>,
+    half_edges: Vec<HalfEdge>,
+    edges: Vec<Edge>,
+    faces: Vec<Face>,
+    vertex_lookup: HashMap<[u64; 4], usize>,
+    // vértices mais próximos que esta distância são fundidos em um só
+    // (zero exige posições idênticas)
+    weld_distance: f64,
+    // vértices agrupados em células do tamanho da distância de fusão
+    vertex_grid: HashMap<[i64; 3], Vec<usize>>,
+    half_edge_lookup: HashMap<(usize, usize), usize>,
+    edge_lookup: HashMap<(usize, usize), usize>,
+}
+
+// chave exata da posição (-0.0 e 0.0 são o mesmo vértice)
+fn key(position: Position) -> [u64; 4] {
+    position.map(|coordinate| (coordinate + 0.0).to_bits())
+}
+
+impl Mesh {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_weld_distance(weld_distance: f64) -> Self {
+        Self {
+            weld_distance,
+            ..Self::default()
+        }
+    }
+
+    // célula da grade de fusão que contém a posição
+    fn cell(&self, position: Position) -> [i64; 3] {
+        [
+            (position[0] / self.weld_distance).floor() as i64,
+            (position[1] / self.weld_distance).floor() as i64,
+            (position[2] / self.weld_distance).floor() as i64,
+        ]
+    }
+
+    // vértice existente a até a distância de fusão da posição, buscando na
+    // célula da posição e nas vizinhas
+    fn find_nearby_vertex(&self, position: Position) -> Option<usize> {
+        let cell = self.cell(position);
+        let mut nearest: Option<(usize, f64)> = None;
+
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                for dz in -1..=1 {
+                    let neighbor = [cell[0] + dx, cell[1] + dy, cell[2] + dz];
+                    for &index in self.vertex_grid.get(&neighbor).into_iter().flatten() {
+                        let other = self.vertices[index].position;
+                        let distance = (0..3)
+                            .map(|i| (other[i] - position[i]).powi(2))
+                            .sum::<f64>()
+                            .sqrt();
+                        if distance <= self.weld_distance &&
+                            nearest.is_none_or(|(_, best)| distance < best)
+                        {
+                            nearest = Some((index, distance));
+                        }
+                    }
+                }
+            }
+        }
+
+        nearest.map(|(index, _)| index)
+    }
+
+    pub fn vertices(&self) -> &[Vertex] {
+        &self.vertices
+    }
+
+    pub fn faces(&self) -> &[Face] {
+        &self.faces
+    }
+
+    // obtém o índice do vértice na posição (ou a até a distância de fusão
+    // dela); caso não exista, adiciona
+    pub fn add_vertex(&mut self, position: Position) -> usize {
+        if let Some(&index) = self.vertex_lookup.get(&key(position)) {
+            return index;
+        }
+
+        let index = self.vertices.len();
+
+        if self.weld_distance > 0.0 {
+            if let Some(nearby) = self.find_nearby_vertex(position) {
+                return nearby;
+            }
+            let cell = self.cell(position);
+            self.vertex_grid.entry(cell).or_default().push(index);
+        }
+
+        self.vertices.push(Vertex {
+            position,
+            half_edge: None,
+        });
+        self.vertex_lookup.insert(key(position), index);
+        index
+    }
+
+    pub fn add_face(&mut self, face: FaceType) -> Option<usize> {
+        let indices = [
+            self.add_vertex(face.0),
+            self.add_vertex(face.1),
+            self.add_vertex(face.2),
+        ];
+
+        // faces com vértices repetidos não têm área
+        if indices[0] == indices[1] || indices[1] == indices[2] || indices[2] == indices[0] {
+            return None;
+        }
+
+        let face_index = self.faces.len();
+        let first = self.half_edges.len();
+
+        for i in 0..3 {
+            let origin = indices[i];
+            let destination = indices[(i + 1) % 3];
+            let half_edge = first + i;
+
+            // liga à semiaresta oposta, se a face vizinha já existir
+            let twin = self.half_edge_lookup
+                .get(&(destination, origin))
+                .copied()
+                .filter(|&twin| self.half_edges[twin].twin.is_none());
+            if let Some(twin) = twin {
+                self.half_edges[twin].twin = Some(half_edge);
+            }
+
+            // a aresta não depende do sentido e guarda as semiarestas que a usam
+            let edge = match self.edge_lookup.get(&(origin.min(destination), origin.max(destination))) {
+                Some(&edge) => {
+                    self.edges[edge].half_edges.push(half_edge);
+                    edge
+                }
+                None => {
+                    self.edges.push(Edge {
+                        vertices: [origin, destination],
+                        half_edges: vec![half_edge],
+                    });
+                    self.edge_lookup.insert(
+                        (origin.min(destination), origin.max(destination)),
+                        self.edges.len() - 1,
+                    );
+                    self.edges.len() - 1
+                }
+            };
+
+            self.half_edges.push(HalfEdge {
+                origin,
+                twin,
+                next: first + (i + 1) % 3,
+                face: face_index,
+                edge,
+            });
+            self.half_edge_lookup.entry((origin, destination)).or_insert(half_edge);
+
+            if self.vertices[origin].half_edge.is_none() {
+                self.vertices[origin].half_edge = Some(half_edge);
+            }
+        }
+
+        self.faces.push(Face {
+            half_edge: first,
+        });
+
+        Some(face_index)
+    }
+
+    pub fn destination(&self, half_edge: usize) -> usize {
+        self.half_edges[self.half_edges[half_edge].next].origin
+    }
+
+    pub fn half_edge(&self, half_edge: usize) -> &HalfEdge {
+        &self.half_edges[half_edge]
+    }
+
+    // as três semiarestas da face, seguindo `next`
+    pub fn face_half_edges(&self, face: usize) -> [usize; 3] {
+        let first = self.faces[face].half_edge;
+        let second = self.half_edges[first].next;
+        let third = self.half_edges[second].next;
+        [first, second, third]
+    }
+
+    pub fn face_vertices(&self, face: usize) -> [usize; 3] {
+        self.face_half_edges(face).map(|half_edge| self.half_edges[half_edge].origin)
+    }
+
+    // faces que usam a aresta
+    pub fn edge_faces(&self, edge: usize) -> impl Iterator<Item = usize> + '_ {
+        self.edges[edge]
+            .half_edges
+            .iter()
+            .map(|&half_edge| self.half_edges[half_edge].face)
+    }
+
+    // vizinhas da face, como pares (semiaresta da face, semiaresta da
+    // vizinha na mesma aresta); a vizinha pode percorrer a aresta no mesmo
+    // sentido se a orientação for inconsistente
+    pub fn neighbors(&self, face: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.face_half_edges(face).into_iter().flat_map(move |half_edge| {
+            self.edges[self.half_edges[half_edge].edge]
+                .half_edges
+                .iter()
+                .filter(move |&&other| other != half_edge)
+                .map(move |&other| (half_edge, other))
+        })
+    }
+
+    // semiarestas que partem do vértice, girando em torno dele pelas
+    // semiarestas opostas; só alcança o leque de faces da semiaresta
+    // guardada no vértice
+    pub fn outgoing_half_edges(&self, vertex: usize) -> Vec<usize> {
+        let start = match self.vertices[vertex].half_edge {
+            Some(start) => start,
+            None => return Vec::new(),
+        };
+
+        // anterior da semiaresta na face, que chega ao vértice
+        let previous = |half_edge: usize| self.half_edges[self.half_edges[half_edge].next].next;
+
+        let mut outgoing = vec![start];
+        let mut current = start;
+        while let Some(twin) = self.half_edges[previous(current)].twin {
+            if twin == start {
+                return outgoing;
+            }
+            outgoing.push(twin);
+            current = twin;
+        }
+
+        // o leque chegou à borda; completa girando no outro sentido
+        current = start;
+        while let Some(twin) = self.half_edges[current].twin {
+            current = self.half_edges[twin].next;
+            if current == start {
+                break;
+            }
+            outgoing.push(current);
+        }
+
+        outgoing
+    }
+
+    // vértice na borda do seu leque de faces
+    pub fn is_boundary_vertex(&self, vertex: usize) -> bool {
+        self.outgoing_half_edges(vertex)
+            .iter()
+            .any(|&half_edge| self.half_edges[half_edge].twin.is_none())
+    }
+
+    // refaz a malha com a orientação de cada face propagada às vizinhas
+    // (uma aresta compartilhada é percorrida em sentidos opostos) e cada
+    // peça conexa voltada para +Z (anti-horária vista de cima)
+    pub fn oriented(&self) -> Mesh {
+        let faces: Vec<[usize; 3]> = (0..self.faces.len())
+            .map(|face| self.face_vertices(face))
+            .collect();
+
+        // None enquanto a face não foi visitada; Some(true) se deve ser invertida
+        let mut flipped: Vec<Option<bool>> = vec![None; faces.len()];
+
+        for start in 0..faces.len() {
+            if flipped[start].is_some() {
+                continue;
+            }
+
+            flipped[start] = Some(false);
+            let mut component = vec![start];
+            let mut queue = VecDeque::from([start]);
+
+            while let Some(face) = queue.pop_front() {
+                for (half_edge, other) in self.neighbors(face) {
+                    let neighbor = self.half_edges[other].face;
+                    if flipped[neighbor].is_none() {
+                        // a vizinha deve percorrer a aresta no sentido
+                        // contrário ao da face, depois de a face ser invertida
+                        let same_direction = self.half_edges[half_edge].origin == self.half_edges[other].origin;
+                        flipped[neighbor] = Some(same_direction != flipped[face].unwrap_or(false));
+                        component.push(neighbor);
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+
+            // área com sinal da peça; negativa quando está voltada para -Z
+            let area: f64 = component
+                .iter()
+                .map(|&face| {
+                    let [a, b, c] = faces[face].map(|vertex| self.vertices[vertex].position);
+                    let cross = (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0]);
+                    if flipped[face] == Some(true) { -cross } else { cross }
+                })
+                .sum();
+            if area < 0.0 {
+                for &face in component.iter() {
+                    flipped[face] = flipped[face].map(|flip| !flip);
+                }
+            }
+        }
+
+        let mut mesh = Mesh::with_weld_distance(self.weld_distance);
+        for (face, vertices) in faces.iter().enumerate() {
+            let [a, b, c] = vertices.map(|vertex| self.vertices[vertex].position);
+            if flipped[face] == Some(true) {
+                mesh.add_face((a, c, b));
+            } else {
+                mesh.add_face((a, b, c));
+            }
+        }
+
+        mesh
+    }
+
+    pub fn edges(&self) -> &[Edge] {
+        &self.edges
+    }
+
+    // arestas usadas por uma única face, no sentido dessa face (com a face
+    // à esquerda)
+    pub fn boundary_edges(&self) -> impl Iterator<Item = [usize; 2]> + '_ {
+        self.edges
+            .iter()
+            .filter(|edge| edge.half_edges.len() == 1)
+            .map(|edge| [
+                self.half_edges[edge.half_edges[0]].origin,
+                self.destination(edge.half_edges[0]),
+            ])
+    }
+
+    // índices das arestas compartilhadas por exatamente duas faces
+    pub fn interior_edges(&self) -> impl Iterator<Item = usize> + '_ {
+        self.edges
+            .iter()
+            .enumerate()
+            .filter(|(_, edge)| edge.half_edges.len() == 2)
+            .map(|(index, _)| index)
+    }
+
+    // laços fechados formados pelas arestas de borda (o contorno externo e
+    // os furos de cada peça), como listas de vértices
+    pub fn contours(&self) -> Vec<Vec<usize>> {
+        let mut outgoing: HashMap<usize, Vec<[usize; 2]>> = HashMap::new();
+        for [origin, destination] in self.boundary_edges() {
+            outgoing.entry(origin).or_default().push([origin, destination]);
+        }
+
+        let mut contours: Vec<Vec<usize>> = Vec::new();
+        for [start, _] in self.boundary_edges() {
+            let mut contour: Vec<usize> = Vec::new();
+            let mut current = start;
+            // segue as arestas de borda até voltar ao início
+            while let Some([origin, destination]) = outgoing
+                .get_mut(&current)
+                .and_then(|edges| edges.pop())
+            {
+                contour.push(origin);
+                current = destination;
+                if current == start {
+                    break;
+                }
+            }
+            if !contour.is_empty() {
+                contours.push(contour);
+            }
+        }
+
+        contours
+    }
+
+    // direção, por vértice, para recuar os contornos (os de `contours()`)
+    // uma unidade para dentro: a bissetriz das normais internas das duas
+    // arestas vizinhas, alongada para manter a mesma distância a ambas
+    pub fn inset_directions(&self, contours: &[Vec<usize>]) -> Vec<Point> {
+        let mut directions = vec![[0.0, 0.0]; self.vertices.len()];
+
+        let inward_normal = |a: Position, b: Position| {
+            let length = (b[0] - a[0]).hypot(b[1] - a[1]);
+            [-(b[1] - a[1]) / length, (b[0] - a[0]) / length]
+        };
+
+        for contour in contours {
+            let n = contour.len();
+            for i in 0..n {
+                let previous = self.vertices[contour[(i + n - 1) % n]].position;
+                let current = self.vertices[contour[i]].position;
+                let next = self.vertices[contour[(i + 1) % n]].position;
+
+                let first = inward_normal(previous, current);
+                let second = inward_normal(current, next);
+                let sum = [first[0] + second[0], first[1] + second[1]];
+                let length = sum[0].hypot(sum[1]);
+
+                // o contorno volta sobre si mesmo; recua pela primeira aresta
+                if length < 1e-9 {
+                    directions[contour[i]] = first;
+                    continue;
+                }
+
+                let bisector = [sum[0] / length, sum[1] / length];
+                let cos = bisector[0] * first[0] + bisector[1] * first[1];
+                let scale = (1.0 / cos).min(MAX_MITER);
+                directions[contour[i]] = [bisector[0] * scale, bisector[1] * scale];
+            }
+        }
+
+        directions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::validate;
+
+    #[test]
+    fn oriented_faces_are_consistent_and_face_up() {
+        let mut mesh = Mesh::new();
+        // quadrado com uma face horária e outra anti-horária, e um
+        // triângulo separado só com faces horárias
+        mesh.add_face(([0.0, 0.0, 0.0, 1.0], [1.0, 1.0, 0.0, 1.0], [1.0, 0.0, 0.0, 1.0]));
+        mesh.add_face(([0.0, 0.0, 0.0, 1.0], [1.0, 1.0, 0.0, 1.0], [0.0, 1.0, 0.0, 1.0]));
+        mesh.add_face(([2.0, 0.0, 0.0, 1.0], [2.5, 1.0, 0.0, 1.0], [3.0, 0.0, 0.0, 1.0]));
+        assert!(!validate(&mesh).is_valid());
+
+        let mesh = mesh.oriented();
+        assert!(validate(&mesh).is_valid());
+        for face in 0..mesh.faces().len() {
+            let [a, b, c] = mesh.face_vertices(face).map(|vertex| mesh.vertices()[vertex].position);
+            let cross = (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0]);
+            assert!(cross > 0.0);
+        }
+    }
+
+    #[test]
+    fn adjacency_follows_the_half_edges() {
+        // hexágono em volta da origem, com seis faces anti-horárias
+        let mut mesh = Mesh::new();
+        let corner = |i: usize| {
+            let angle = i as f64 * std::f64::consts::PI / 3.0;
+            [angle.cos(), angle.sin(), 0.0, 1.0]
+        };
+        for i in 0..6 {
+            mesh.add_face(([0.0, 0.0, 0.0, 1.0], corner(i), corner((i + 1) % 6)));
+        }
+
+        let center = mesh.face_vertices(0)[0];
+        assert_eq!(mesh.outgoing_half_edges(center).len(), 6);
+        assert!(!mesh.is_boundary_vertex(center));
+
+        let corner = mesh.face_vertices(0)[1];
+        assert_eq!(mesh.outgoing_half_edges(corner).len(), 2);
+        assert!(mesh.is_boundary_vertex(corner));
+
+        let mut neighbors: Vec<usize> = mesh
+            .neighbors(0)
+            .map(|(_, other)| mesh.half_edge(other).face)
+            .collect();
+        neighbors.sort();
+        assert_eq!(neighbors, vec![1, 5]);
+
+        // os raios são internos e os lados do hexágono, de borda
+        assert_eq!(mesh.interior_edges().count(), 6);
+        assert!(mesh
+            .interior_edges()
+            .all(|edge| mesh.edges()[edge].vertices.contains(&center)));
+        assert_eq!(mesh.boundary_edges().count(), 6);
+    }
+
+    #[test]
+    fn nearby_vertices_are_welded() {
+        let faces = [
+            ([0.0, 0.0, 0.0, 1.0], [1.0, 0.0, 0.0, 1.0], [1.0, 1.0, 0.0, 1.0]),
+            ([1e-9, 0.0, 0.0, 1.0], [1.0, 1.0 + 1e-9, 0.0, 1.0], [0.0, 1.0, 0.0, 1.0]),
+        ];
+
+        let mut exact = Mesh::new();
+        let mut welded = Mesh::with_weld_distance(1e-6);
+        for face in faces {
+            exact.add_face(face);
+            welded.add_face(face);
+        }
+
+        assert_eq!(exact.vertices().len(), 6);
+        assert_eq!(welded.vertices().len(), 4);
+        assert_eq!(welded.boundary_edges().count(), 4);
+    }
+}
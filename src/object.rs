@@ -1,77 +1,190 @@
-use std::rc::Rc;
-use std::cell::RefCell;
-
-use crate::types::{
-    Position,
-    FaceType,
-    Matrix4x1,
-    Matrix4x4,
-    Rotation,
-    Scale,
-    ShaderVertex,
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    },
 };
 
-#[derive(Debug, Clone, Copy)]
-pub struct Vertex {
-    pub position: Position,
+use crate::{
+    constants::{
+        DEFAULT_CREASE_ANGLE,
+        DEFAULT_TOLERANCE,
+        DEFAULT_WELD_DISTANCE,
+    },
+    mesh::Mesh,
+    outline::tessellate,
+    triangulation::triangulate,
+    types::{
+        Position,
+        Matrix3x3,
+        Matrix4x4,
+        Point,
+        Quaternion,
+        Scale,
+        Segment,
+        ShaderVertex,
+    },
+    validation::{
+        validate,
+        ValidationReport,
+    },
+};
+
+// ponto em torno do qual o objeto gira e muda de escala
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Pivot {
+    // origem do glifo
+    Origin,
+    // centro da caixa envolvente da malha
+    BoundingBoxCenter,
+    // posição do cursor do texto no momento em que foi escolhido
+    Cursor([f64; 3]),
+    // ponto qualquer da cena
+    Custom([f64; 3]),
 }
 
-#[derive(Debug)]
-pub struct Edge {
-    origin_vertex: Rc<RefCell<Vertex>>,
-    destination_vertex: Rc<RefCell<Vertex>>,
+// eixos ao longo dos quais a escala é aplicada
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScaleSpace {
+    // eixos do próprio glifo, antes da rotação
+    Local,
+    // eixos da cena, depois da rotação
+    Rotated,
 }
 
-#[derive(Debug)]
-pub struct Face {
-    start_edge: Rc<Edge>,
-    middle_edge: Rc<Edge>,
-    end_edge: Rc<Edge>,
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Shading {
+    // uma normal por face
+    Flat,
+    // normais médias nos vértices, exceto entre faces que formam um ângulo
+    // maior que o ângulo de vinco
+    Smooth,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BevelProfile {
+    Flat,
+    Rounded,
+}
+
+// chanfro nas bordas das duas tampas de um objeto extrudado
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bevel {
+    // distância em z entre a tampa e o início da parede lateral
+    pub depth: f64,
+    // quanto o contorno da tampa recua para dentro
+    pub width: f64,
+    pub segments: usize,
+    pub profile: BevelProfile,
+}
+
+// próximo identificador livre, que distingue os objetos entre atualizações
+// mesmo quando mudam de posição no texto
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+// o que mudou no objeto desde que a aplicação o enviou à GPU pela última vez
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Changes {
+    // vértices e índices, no espaço do glifo
+    pub geometry: bool,
+    // matriz de modelo
+    pub transform: bool,
 }
 
 #[derive(Debug)]
 pub struct Object {
-    vertices: Vec<Rc<RefCell<Vertex>>>,
-    edges: Vec<Rc<Edge>>,
-    faces: Vec<Face>,
+    id: usize,
+    mesh: Mesh,
+    // contornos de origem da malha
+    outline: Vec<Vec<Segment>>,
+    pub tolerance: f64,
+    pub weld_distance: f64,
+    // validação da malha, refeita só quando a malha muda
+    report: ValidationReport,
+    // posição definida pela diagramação do texto, somada à posição
+    origin: Point,
     pub position: Position,
-    pub rotation: Rotation,
+    pub rotation: Quaternion,
     pub scale: Scale,
+    pub pivot: Pivot,
+    pub scale_space: ScaleSpace,
     pub extrusion: Option<f64>,
+    pub bevel: Option<Bevel>,
+    pub shading: Shading,
+    // em graus
+    pub crease_angle: f64,
     vertex_buffer: Vec<ShaderVertex>,
     triangle_index_buffer: Vec<u32>,
     line_index_buffer: Vec<u32>,
+    // caixa dos vértices do glifo, refeita junto com os buffers
+    bounding_box: Option<([f64; 3], [f64; 3])>,
+    changes: Changes,
 }
 
 impl Object{
     pub fn new(position: Position) -> Self {
         Self {
-            vertices: Vec::new(),
-            edges: Vec::new(),
-            faces: Vec::new(),
-            position: position,
-            rotation: [0.0, 0.0, 0.0],
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            mesh: Mesh::new(),
+            outline: Vec::new(),
+            tolerance: DEFAULT_TOLERANCE,
+            weld_distance: DEFAULT_WELD_DISTANCE,
+            report: ValidationReport::default(),
+            origin: [0.0, 0.0],
+            position,
+            rotation: Quaternion::identity(),
             scale: [1.0, 1.0, 1.0],
+            pivot: Pivot::Origin,
+            scale_space: ScaleSpace::Rotated,
             extrusion: None,
+            bevel: None,
+            shading: Shading::Smooth,
+            crease_angle: DEFAULT_CREASE_ANGLE,
             vertex_buffer: Vec::new(),
             triangle_index_buffer: Vec::new(),
             line_index_buffer: Vec::new(),
+            bounding_box: None,
+            changes: Changes {
+                geometry: true,
+                transform: true,
+            },
         }
     }
 
     pub fn set_scale(&mut self, scale: Scale) {
         self.scale = scale;
-        self.update_buffers();
+        self.changes.transform = true;
     }
 
     pub fn set_position(&mut self, position: Position) {
         self.position = position;
-        self.update_buffers();
+        self.changes.transform = true;
     }
 
-    pub fn set_rotation(&mut self, rotation: Rotation) {
+    pub fn set_origin(&mut self, origin: Point) {
+        if self.origin != origin {
+            self.origin = origin;
+            self.changes.transform = true;
+        }
+    }
+
+    pub fn set_rotation(&mut self, rotation: Quaternion) {
         self.rotation = rotation;
-        self.update_buffers();
+        self.changes.transform = true;
+    }
+
+    pub fn set_pivot(&mut self, pivot: Pivot) {
+        self.pivot = pivot;
+        self.changes.transform = true;
+    }
+
+    pub fn set_scale_space(&mut self, scale_space: ScaleSpace) {
+        self.scale_space = scale_space;
+        self.changes.transform = true;
     }
 
     pub fn set_extrusion(&mut self, extrusion: Option<f64>) {
@@ -79,158 +192,137 @@ impl Object{
         self.update_buffers();
     }
 
-    pub fn add_face(&mut self, face: FaceType) {
-        // obtém o index de cada vértice
-        // caso algum não exista, adiciona
-        let v0_index = match self.vertices.iter().position(|vertex|
-                face.0 == vertex.borrow().position
-        ) {
-            Some(index) => {
-                index
-            }
-            None => {
-                self.vertices.push(Rc::new(RefCell::new(
-                    Vertex {
-                        position: face.0,
-                    }
-                )));
-                self.vertices.len() - 1
-            }
-        };
+    pub fn set_bevel(&mut self, bevel: Option<Bevel>) {
+        self.bevel = bevel;
+        self.update_buffers();
+    }
 
-        let v1_index = match self.vertices.iter().position(|vertex|
-            face.1 == vertex.borrow().position
-        ) {
-            Some(index) => {
-                index
-            }
-            None => {
-                self.vertices.push(Rc::new(RefCell::new(
-                    Vertex {
-                        position: face.1,
-                    }
-                )));
-                self.vertices.len() - 1
-            }
-        };
+    pub fn set_shading(&mut self, shading: Shading) {
+        self.shading = shading;
+        self.update_buffers();
+    }
 
-        let v2_index = match self.vertices.iter().position(|vertex|
-            face.2 == vertex.borrow().position
-        ) {
-            Some(index) => {
-                index
-            }
-            None => {
-                self.vertices.push(Rc::new(RefCell::new(
-                    Vertex {
-                        position: face.2,
-                    }
-                )));
-                self.vertices.len() - 1
-            }
-        };
+    pub fn set_crease_angle(&mut self, crease_angle: f64) {
+        self.crease_angle = crease_angle;
+        self.update_buffers();
+    }
 
-        // obtém o index de cada aresta
-        // caso alguma não exista, adiciona
-        let e0_index = match self.edges.iter().position(|edge|
-            (face.0 == edge.origin_vertex.borrow().position &&
-            face.1 == edge.destination_vertex.borrow().position) ||
-            (face.1 == edge.origin_vertex.borrow().position &&
-            face.0 == edge.destination_vertex.borrow().position) 
-        ) {
-            Some(index) => {
-                index
-            }
-            None => {
-                self.edges.push(Rc::new(
-                    Edge {
-                        origin_vertex: Rc::clone(&self.vertices[v0_index]),
-                        destination_vertex: Rc::clone(&self.vertices[v1_index]),
-                    }
-                ));
-                self.edges.len() - 1
-            }
-        };
+    pub fn set_outline(&mut self, outline: &[Vec<Segment>]) {
+        self.outline = outline.to_vec();
+        self.rebuild_mesh();
+    }
 
-        let e1_index = match self.edges.iter().position(|edge|
-            (face.1 == edge.origin_vertex.borrow().position &&
-            face.2 == edge.destination_vertex.borrow().position) ||
-            (face.2 == edge.origin_vertex.borrow().position &&
-            face.1 == edge.destination_vertex.borrow().position)
-        ) {
-            Some(index) => {
-                index
-            }
-            None => {
-                self.edges.push(Rc::new(
-                    Edge {
-                        origin_vertex: Rc::clone(&self.vertices[v1_index]),
-                        destination_vertex: Rc::clone(&self.vertices[v2_index]),
-                    }
-                ));
-                self.edges.len() - 1
-            }
-        };
+    pub fn set_tolerance(&mut self, tolerance: f64) {
+        self.tolerance = tolerance;
+        self.rebuild_mesh();
+    }
 
-        let e2_index = match self.edges.iter().position(|edge|
-            (face.2 == edge.origin_vertex.borrow().position &&
-            face.0 == edge.destination_vertex.borrow().position) ||
-            (face.0 == edge.origin_vertex.borrow().position &&
-            face.2 == edge.destination_vertex.borrow().position) 
-        ) {
-            Some(index) => {
-                index
-            }
-            None => {
-                self.edges.push(Rc::new(
-                    Edge {
-                        origin_vertex: Rc::clone(&self.vertices[v2_index]),
-                        destination_vertex: Rc::clone(&self.vertices[v0_index]),
-                    }
-                ));
-                self.edges.len() - 1
-            }
-        };
+    // funde os vértices mais próximos que a distância indicada, que passa a
+    // valer também quando a malha for refeita
+    pub fn merge_by_distance(&mut self, distance: f64) {
+        self.weld_distance = distance;
+        self.rebuild_mesh();
+    }
 
-        self.faces.push(
-            Face {
-                start_edge: Rc::clone(&self.edges[e0_index]),
-                middle_edge: Rc::clone(&self.edges[e1_index]),
-                end_edge: Rc::clone(&self.edges[e2_index]),
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    // devolve e limpa as mudanças acumuladas
+    pub fn take_changes(&mut self) -> Changes {
+        std::mem::replace(&mut self.changes, Changes {
+            geometry: false,
+            transform: false,
+        })
+    }
+
+    // matriz que leva os vértices do glifo à cena e a que leva as normais
+    // (a inversa transposta da parte linear, que as mantém perpendiculares
+    // às faces com escalas diferentes por eixo), em colunas como esperam os
+    // shaders
+    pub fn model_matrix(&self) -> ([[f32; 4]; 4], [[f32; 3]; 3]) {
+        let matrix = self.gen_transform_matriz();
+        (columns(&matrix), normal_columns(&matrix))
+    }
+
+    // caixa que envolve os vértices do glifo, com a extrusão; None se o
+    // objeto não tiver vértices
+    pub fn bounding_box(&self) -> Option<([f64; 3], [f64; 3])> {
+        self.bounding_box
+    }
+
+    // caixa alinhada aos eixos da cena que envolve o objeto transformado
+    pub fn scene_bounding_box(&self) -> Option<([f64; 3], [f64; 3])> {
+        let (min, max) = self.bounding_box()?;
+        let matrix = self.gen_transform_matriz();
+
+        let mut scene_min = [f64::INFINITY; 3];
+        let mut scene_max = [f64::NEG_INFINITY; 3];
+        for corner in 0..8 {
+            let local = nalgebra::Vector4::new(
+                if corner & 1 == 0 { min[0] } else { max[0] },
+                if corner & 2 == 0 { min[1] } else { max[1] },
+                if corner & 4 == 0 { min[2] } else { max[2] },
+                1.0,
+            );
+            let point = matrix * local;
+            for i in 0..3 {
+                scene_min[i] = scene_min[i].min(point[i]);
+                scene_max[i] = scene_max[i].max(point[i]);
             }
+        }
+        Some((scene_min, scene_max))
+    }
+
+    // matriz de modelo que leva o cubo de 0 a 1 à caixa do objeto, afastada
+    // dele por `margin`, para desenhar o contorno de destaque
+    pub fn bounding_box_matrix(&self, margin: f64) -> Option<[[f32; 4]; 4]> {
+        let (min, max) = self.bounding_box()?;
+        let size = sub(max, min).map(|length| length + 2.0 * margin);
+        let scaling = Matrix4x4::new(
+            size[0], 0.0, 0.0, 0.0,
+            0.0, size[1], 0.0, 0.0,
+            0.0, 0.0, size[2], 0.0,
+            0.0, 0.0, 0.0, 1.0,
         );
+        let matrix = self.gen_transform_matriz() *
+            translation(min.map(|coordinate| coordinate - margin)) *
+            scaling;
 
-        self.update_buffers();
+        Some(columns(&matrix))
     }
 
-    fn gen_x_rotation_matriz(&self) -> Matrix4x4 {
-        let rotation = (self.rotation[0] * std::f64::consts::PI) / 180.0;
-        Matrix4x4::new(
-            1.0, 0.0, 0.0, 0.0,
-            0.0, rotation.cos(), -rotation.sin(), 0.0,
-            0.0, rotation.sin(), rotation.cos(), 0.0,
-            0.0, 0.0, 0.0, 1.0,
-        )
+    pub fn vertex_count(&self) -> usize {
+        self.mesh.vertices().len()
     }
-    
-    fn gen_y_rotation_matriz(&self) -> Matrix4x4 {
-        let rotation = (self.rotation[1] * std::f64::consts::PI) / 180.0;
-        Matrix4x4::new(
-            rotation.cos(), 0.0, rotation.sin(), 0.0,
-            0.0, 1.0, 0.0, 0.0,
-            -rotation.sin(), 0.0, rotation.cos(), 0.0,
-            0.0, 0.0, 0.0, 1.0,
-        )
+
+    // problemas encontrados na malha atual (faces repetidas ou sem área,
+    // orientação inconsistente, arestas não variedade e junções em T)
+    pub fn report(&self) -> &ValidationReport {
+        &self.report
     }
-    
-    fn gen_z_rotation_matriz(&self) -> Matrix4x4 {
-        let rotation = (self.rotation[2] * std::f64::consts::PI) / 180.0;
-        Matrix4x4::new(
-            rotation.cos(), -rotation.sin(), 0.0, 0.0,
-            rotation.sin(), rotation.cos(), 0.0, 0.0,
-            0.0, 0.0, 1.0, 0.0,
-            0.0, 0.0, 0.0, 1.0,
-        )
+
+    // refaz a malha a partir dos contornos com a tolerância atual
+    fn rebuild_mesh(&mut self) {
+        self.mesh = Mesh::with_weld_distance(self.weld_distance);
+
+        for face in tessellate(&self.outline, self.tolerance) {
+            self.mesh.add_face(face);
+        }
+        self.orient_faces();
+        self.report = validate(&self.mesh);
+
+        self.update_buffers();
+    }
+
+    // deixa todas as faces de cada peça com a mesma orientação, voltadas
+    // para +Z, como esperam as tampas e as paredes da extrusão
+    fn orient_faces(&mut self) {
+        self.mesh = self.mesh.oriented();
+    }
+
+    fn gen_rotation_matriz(&self) -> Matrix4x4 {
+        self.rotation.to_homogeneous()
     }
 
     fn gen_scaling_matriz(&self) -> Matrix4x4 {
@@ -243,128 +335,395 @@ impl Object{
     }
 
     fn gen_translation_matriz(&self) -> Matrix4x4 {
-        Matrix4x4::new(
-            1.0, 0.0, 0.0, self.position[0],
-            0.0, 1.0, 0.0, self.position[1],
-            0.0, 0.0, 1.0, self.position[2],
-            0.0, 0.0, 0.0, 1.0,
-        )
+        translation([
+            self.origin[0] + self.position[0],
+            self.origin[1] + self.position[1],
+            self.position[2],
+        ])
     }
 
-    fn update_buffers(&mut self) {
-        let x_rotation_matrix: Matrix4x4 = self.gen_x_rotation_matriz();
-        let y_rotation_matrix: Matrix4x4 = self.gen_y_rotation_matriz();
-        let z_rotation_matrix: Matrix4x4 = self.gen_z_rotation_matriz();
+    // pivô na cena, já com o objeto posicionado
+    pub fn pivot_point(&self) -> [f64; 3] {
+        let placed = |point: [f64; 3]| [
+            point[0] + self.origin[0] + self.position[0],
+            point[1] + self.origin[1] + self.position[1],
+            point[2] + self.position[2],
+        ];
+
+        match self.pivot {
+            Pivot::Origin => placed([0.0, 0.0, 0.0]),
+            // a caixa já calculada tem em x e y os limites da malha
+            Pivot::BoundingBoxCenter => match self.bounding_box {
+                // a extrusão é simétrica em torno de z = 0
+                Some((min, max)) => placed([(min[0] + max[0]) / 2.0, (min[1] + max[1]) / 2.0, 0.0]),
+                None => placed([0.0, 0.0, 0.0]),
+            },
+            Pivot::Cursor(point) | Pivot::Custom(point) => point,
+        }
+    }
+
+    // posiciona o objeto e aplica a rotação e a escala em torno do pivô
+    fn gen_transform_matriz(&self) -> Matrix4x4 {
+        let rotation_matrix: Matrix4x4 = self.gen_rotation_matriz();
         let scaling_matrix: Matrix4x4 = self.gen_scaling_matriz();
         let translation_matrix: Matrix4x4 = self.gen_translation_matriz();
 
+        let pivot = self.pivot_point();
+        let to_pivot = translation(pivot);
+        let from_pivot = translation(pivot.map(|coordinate| -coordinate));
+
+        let linear = match self.scale_space {
+            ScaleSpace::Local => rotation_matrix * scaling_matrix,
+            ScaleSpace::Rotated => scaling_matrix * rotation_matrix,
+        };
+
+        to_pivot * linear * from_pivot * translation_matrix
+    }
+
+    // perfil da lateral na metade da frente, do topo da parede até a
+    // tampa, como pares (recuo para dentro, z)
+    fn extrusion_profile(&self, extrusion: f64) -> Vec<(f64, f64)> {
+        let half = extrusion / 2.0;
+
+        match self.bevel {
+            Some(bevel) => {
+                // com extrusão negativa ou inválida, `clamp` entraria em pânico
+                let depth = bevel.depth.max(0.0).min(half.abs());
+                let segments = bevel.segments.max(1);
+                (0..=segments)
+                    .map(|segment| {
+                        let t = segment as f64 / segments as f64;
+                        let (inset, rise) = match bevel.profile {
+                            BevelProfile::Flat => (t, t),
+                            BevelProfile::Rounded => {
+                                let angle = t * std::f64::consts::FRAC_PI_2;
+                                (1.0 - angle.cos(), angle.sin())
+                            }
+                        };
+                        (bevel.width * inset, half - depth + depth * rise)
+                    })
+                    .collect()
+            }
+            None => vec![(0.0, half)],
+        }
+    }
+
+    // faces e arestas das tampas; com o chanfro, os contornos recuados são
+    // triangulados de novo para que nenhum triângulo fino se inverta
+    fn caps(
+        &self,
+        inset: f64,
+        directions: &[Point],
+        contours: &[Vec<usize>],
+    ) -> (Vec<[usize; 3]>, Vec<[usize; 2]>) {
+        let mesh_caps = || (
+            (0..self.mesh.faces().len()).map(|face| self.mesh.face_vertices(face)).collect(),
+            self.mesh.edges().iter().map(|edge| edge.vertices).collect(),
+        );
+
+        if inset == 0.0 {
+            return mesh_caps();
+        }
+
+        let mut indices: HashMap<[u64; 2], usize> = HashMap::new();
+        let contours: Vec<Vec<Point>> = contours
+            .iter()
+            .map(|contour| {
+                contour
+                    .iter()
+                    .map(|&index| {
+                        let position = self.mesh.vertices()[index].position;
+                        let point = [
+                            position[0] + directions[index][0] * inset,
+                            position[1] + directions[index][1] * inset,
+                        ];
+                        indices.insert(point.map(f64::to_bits), index);
+                        point
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let faces: Vec<[usize; 3]> = triangulate(&contours)
+            .iter()
+            .map(|face| [face.0, face.1, face.2].map(|position| {
+                indices[&[position[0].to_bits(), position[1].to_bits()]]
+            }))
+            .collect();
+
+        // um recuo maior que a espessura do traço cruza os contornos e a
+        // triangulação não fecha com as laterais; nesse caso as tampas
+        // mantêm as faces da malha, só com os vértices deslocados
+        let sides: HashSet<[usize; 2]> = faces
+            .iter()
+            .flat_map(|face| [[face[0], face[1]], [face[1], face[2]], [face[2], face[0]]])
+            .collect();
+        if faces.len() != self.mesh.faces().len() ||
+            !self.mesh.boundary_edges().all(|edge| sides.contains(&edge))
+        {
+            return mesh_caps();
+        }
+
+        let mut edges: Vec<[usize; 2]> = Vec::new();
+        let mut known: HashSet<[usize; 2]> = HashSet::new();
+        for face in faces.iter() {
+            for i in 0..3 {
+                let (a, b) = (face[i], face[(i + 1) % 3]);
+                if known.insert([a.min(b), a.max(b)]) {
+                    edges.push([a, b]);
+                }
+            }
+        }
+
+        (faces, edges)
+    }
+
+    fn update_buffers(&mut self) {
         self.vertex_buffer = Vec::new();
         self.line_index_buffer = Vec::new();
         self.triangle_index_buffer = Vec::new();
 
-        let mut vertices: Vec<Position>;
+        // os contornos são percorridos uma vez e usados pelo recuo, pelas
+        // tampas e por todas as camadas da lateral
+        let contours = self.mesh.contours();
+        let boundary_edges: Vec<[usize; 2]> = self.mesh.boundary_edges().collect();
+
+        let vertices: Vec<Position>;
+        let mut cap_inset = 0.0;
+        let directions = match self.extrusion {
+            Some(_) => self.mesh.inset_directions(&contours),
+            None => Vec::new(),
+        };
+        // camadas de vértices: a tampa de trás, os anéis do chanfro de trás,
+        // os da frente e a tampa da frente
+        let layers: usize;
 
         if let Some(extrusion) = self.extrusion {
-            vertices = self.vertices
+            let profile = self.extrusion_profile(extrusion);
+            cap_inset = profile[profile.len() - 1].0;
+
+            let steps: Vec<(f64, f64)> = profile
                 .iter()
-                .map(|v| [
-                    v.borrow().position[0],
-                    v.borrow().position[1],
-                    -extrusion / 2.0,
-                    1.0,
-                ])
+                .rev()
+                .map(|&(inset, z)| (inset, -z))
+                .chain(profile.iter().copied())
                 .collect();
+            layers = steps.len();
 
-            let mut extruded_vertices: Vec<Position> = Vec::new();
-            for v in vertices.to_vec() {
-                extruded_vertices.push([
-                    v[0],
-                    v[1],
-                    extrusion / 2.0,
-                    1.0,
-                ]);
-            }
-            vertices.extend(extruded_vertices);
+            vertices = steps
+                .iter()
+                .flat_map(|&(inset, z)| {
+                    self.mesh.vertices()
+                        .iter()
+                        .zip(directions.iter())
+                        .map(move |(v, direction)| [
+                            v.position[0] + direction[0] * inset,
+                            v.position[1] + direction[1] * inset,
+                            z,
+                            1.0,
+                        ])
+                })
+                .collect();
         } else {
-            vertices = self.vertices
+            layers = 1;
+            vertices = self.mesh.vertices()
                 .iter()
-                .map(|v| v.borrow().position)
+                .map(|v| v.position)
                 .collect();
         }
 
+        // os vértices ficam no espaço do glifo; a matriz de modelo é aplicada
+        // nos shaders
         for vertex in vertices {
-            let mut vertex_matrix: Matrix4x1 = Matrix4x1::from_vec(
-                vertex.to_vec(),
-            );
-
-            vertex_matrix = x_rotation_matrix * vertex_matrix;
-            vertex_matrix = y_rotation_matrix * vertex_matrix;
-            vertex_matrix = z_rotation_matrix * vertex_matrix;
-            vertex_matrix = scaling_matrix * vertex_matrix;
-            vertex_matrix = translation_matrix * vertex_matrix;
-
             self.vertex_buffer.push(ShaderVertex {
                 position: [
-                    vertex_matrix[0] as f32,
-                    vertex_matrix[1] as f32,
-                    vertex_matrix[2] as f32,
+                    vertex[0] as f32,
+                    vertex[1] as f32,
+                    vertex[2] as f32,
                 ],
                 color: [
                     1.0,
                     1.0,
                     1.0,
-                ]
+                ],
+                normal: [
+                    0.0,
+                    0.0,
+                    0.0,
+                ],
             });
         }
 
-        for edge in self.edges.iter() {
-            let origin_index = self.vertices.iter()
-                .position(|vertex| Rc::ptr_eq(vertex, &edge.origin_vertex))
-                .unwrap();
+        let vert_len = self.mesh.vertices().len();
+        let last = (layers - 1) * vert_len;
 
-            let destination_index = self.vertices.iter()
-                .position(|vertex| Rc::ptr_eq(vertex, &edge.destination_vertex))
-                .unwrap();
+        let (cap_faces, cap_edges) = self.caps(cap_inset, &directions, &contours);
 
+        for [origin_index, destination_index] in cap_edges {
             self.line_index_buffer.push(origin_index as u32);
             self.line_index_buffer.push(destination_index as u32);
 
             if self.extrusion.is_some() {
-                let vert_len = self.vertices.len();
+                self.line_index_buffer.push((origin_index + last) as u32);
+                self.line_index_buffer.push((destination_index + last) as u32);
+            }
+        }
 
-                self.line_index_buffer.push((origin_index + vert_len) as u32);
-                self.line_index_buffer.push((destination_index + vert_len) as u32);
+        // cada par de camadas vizinhas forma uma faixa da lateral: os
+        // anéis do chanfro e, entre eles, a parede
+        for layer in 0..layers - 1 {
+            let lower = layer * vert_len;
+            let upper = (layer + 1) * vert_len;
+
+            // cada vértice dos contornos (externos e furos) liga as camadas
+            for contour in contours.iter() {
+                for &index in contour {
+                    self.line_index_buffer.push((index + lower) as u32);
+                    self.line_index_buffer.push((index + upper) as u32);
+                }
+            }
 
-                if Rc::strong_count(&edge) == 2 {
-                    self.line_index_buffer.push(origin_index as u32);
-                    self.line_index_buffer.push((origin_index + vert_len) as u32);
+            // arestas de borda pertencem a uma única face e ganham um
+            // quadrilátero entre as camadas, dividido pela diagonal; como a
+            // face fica à esquerda da aresta, a ordem (origem, destino)
+            // deixa a lateral voltada para fora
+            for &[origin_index, destination_index] in boundary_edges.iter() {
+                self.line_index_buffer.push((origin_index + lower) as u32);
+                self.line_index_buffer.push((destination_index + upper) as u32);
+
+                if layer > 0 {
+                    self.line_index_buffer.push((origin_index + lower) as u32);
+                    self.line_index_buffer.push((destination_index + lower) as u32);
+                }
 
-                    self.line_index_buffer.push(destination_index as u32);
-                    self.line_index_buffer.push((destination_index + vert_len) as u32);
+                self.triangle_index_buffer.extend([
+                    (origin_index + lower) as u32,
+                    (destination_index + lower) as u32,
+                    (destination_index + upper) as u32,
+                    (origin_index + lower) as u32,
+                    (destination_index + upper) as u32,
+                    (origin_index + upper) as u32,
+                ]);
+            }
+        }
 
-                    self.line_index_buffer.push(origin_index as u32);
-                    self.line_index_buffer.push((destination_index + vert_len) as u32);
-                }
+        for [a, b, c] in cap_faces {
+            if self.extrusion.is_some() {
+                // tampa da frente (z positivo) no sentido anti-horário e
+                // tampa de trás invertida, voltada para z negativo
+                self.triangle_index_buffer.extend([
+                    (a + last) as u32,
+                    (b + last) as u32,
+                    (c + last) as u32,
+                    a as u32,
+                    c as u32,
+                    b as u32,
+                ]);
+            } else {
+                self.triangle_index_buffer.extend([a as u32, b as u32, c as u32]);
             }
         }
 
-        for face in self.faces.iter() {
-            let start_edge_index = self.vertices.iter()
-                .position(|vertex| Rc::ptr_eq(vertex, &face.start_edge.origin_vertex))
-                .unwrap();
+        self.add_shading_vertices();
+        self.bounding_box = self.compute_bounding_box();
+
+        // o centro da caixa envolvente, usado como pivô, pode ter mudado
+        self.changes = Changes {
+            geometry: true,
+            transform: true,
+        };
+    }
+
+    fn compute_bounding_box(&self) -> Option<([f64; 3], [f64; 3])> {
+        if self.vertex_buffer.is_empty() {
+            return None;
+        }
+
+        let mut min = [f64::INFINITY; 3];
+        let mut max = [f64::NEG_INFINITY; 3];
+        for vertex in self.vertex_buffer.iter() {
+            for i in 0..3 {
+                min[i] = min[i].min(vertex.position[i] as f64);
+                max[i] = max[i].max(vertex.position[i] as f64);
+            }
+        }
+        Some((min, max))
+    }
 
-            let middle_edge_index = self.vertices.iter()
-                .position(|vertex| Rc::ptr_eq(vertex, &face.middle_edge.origin_vertex))
-                .unwrap();
+    // os triângulos passam a usar vértices próprios, com normais: um por
+    // canto no sombreamento plano, ou compartilhados entre as faces de um
+    // mesmo vértice que não estão separadas por um vinco
+    fn add_shading_vertices(&mut self) {
+        let positions: Vec<[f64; 3]> = self.vertex_buffer
+            .iter()
+            .map(|vertex| vertex.position.map(|coordinate| coordinate as f64))
+            .collect();
+
+        let triangles: Vec<[usize; 3]> = self.triangle_index_buffer
+            .chunks(3)
+            .map(|triangle| [triangle[0] as usize, triangle[1] as usize, triangle[2] as usize])
+            .collect();
+
+        // normais das faces, com o comprimento proporcional à área
+        let face_normals: Vec<[f64; 3]> = triangles
+            .iter()
+            .map(|&[a, b, c]| {
+                let u = sub(positions[b], positions[a]);
+                let v = sub(positions[c], positions[a]);
+                [
+                    u[1] * v[2] - u[2] * v[1],
+                    u[2] * v[0] - u[0] * v[2],
+                    u[0] * v[1] - u[1] * v[0],
+                ]
+            })
+            .collect();
 
-            let end_edge_index = self.vertices.iter()
-                .position(|vertex| Rc::ptr_eq(vertex, &face.end_edge.origin_vertex))
-                .unwrap();
+        let mut incident_faces: Vec<Vec<usize>> = vec![Vec::new(); positions.len()];
+        for (face, triangle) in triangles.iter().enumerate() {
+            for &index in triangle {
+                incident_faces[index].push(face);
+            }
+        }
 
-            self.triangle_index_buffer.push(start_edge_index as u32);
-            self.triangle_index_buffer.push(middle_edge_index as u32);
-            self.triangle_index_buffer.push(end_edge_index as u32);
+        let min_cos = self.crease_angle.to_radians().cos();
+        let mut shading_vertices: HashMap<(usize, [u64; 3]), u32> = HashMap::new();
+        let mut triangle_index_buffer: Vec<u32> = Vec::with_capacity(self.triangle_index_buffer.len());
+
+        for (face, triangle) in triangles.iter().enumerate() {
+            let face_normal = normalize(face_normals[face]);
+
+            for &index in triangle {
+                let normal = match self.shading {
+                    Shading::Flat => face_normal,
+                    Shading::Smooth => normalize(
+                        incident_faces[index]
+                            .iter()
+                            .filter(|&&other| dot(normalize(face_normals[other]), face_normal) >= min_cos)
+                            .fold([0.0; 3], |sum, &other| add(sum, face_normals[other])),
+                    ),
+                };
+
+                let shading_index = match self.shading {
+                    // no plano, cada canto tem o seu vértice
+                    Shading::Flat => None,
+                    Shading::Smooth => shading_vertices.get(&(index, normal.map(f64::to_bits))).copied(),
+                };
+
+                let shading_index = shading_index.unwrap_or_else(|| {
+                    self.vertex_buffer.push(ShaderVertex {
+                        normal: normal.map(|coordinate| coordinate as f32),
+                        ..self.vertex_buffer[index]
+                    });
+                    let shading_index = (self.vertex_buffer.len() - 1) as u32;
+                    shading_vertices.insert((index, normal.map(f64::to_bits)), shading_index);
+                    shading_index
+                });
+
+                triangle_index_buffer.push(shading_index);
+            }
         }
+
+        self.triangle_index_buffer = triangle_index_buffer;
     }
 
     pub fn get_vertices_vec(&self) -> Vec<ShaderVertex> {
@@ -378,4 +737,174 @@ impl Object{
     pub fn get_triangles_indices_vec(&self) -> Vec<u32> {
         self.triangle_index_buffer.to_vec()
     }
-}
\ No newline at end of file
+}
+
+fn translation(offset: [f64; 3]) -> Matrix4x4 {
+    Matrix4x4::new(
+        1.0, 0.0, 0.0, offset[0],
+        0.0, 1.0, 0.0, offset[1],
+        0.0, 0.0, 1.0, offset[2],
+        0.0, 0.0, 0.0, 1.0,
+    )
+}
+
+// matriz em colunas, como esperam os shaders
+fn columns(matrix: &Matrix4x4) -> [[f32; 4]; 4] {
+    let mut columns = [[0.0; 4]; 4];
+    for (column, values) in columns.iter_mut().enumerate() {
+        for (row, value) in values.iter_mut().enumerate() {
+            *value = matrix[(row, column)] as f32;
+        }
+    }
+    columns
+}
+
+fn normal_columns(matrix: &Matrix4x4) -> [[f32; 3]; 3] {
+    // com escala nula não há inversa; as normais ficam como estão
+    let normal = matrix
+        .fixed_view::<3, 3>(0, 0)
+        .into_owned()
+        .try_inverse()
+        .unwrap_or_else(Matrix3x3::identity)
+        .transpose();
+
+    let mut columns = [[0.0; 3]; 3];
+    for (column, values) in columns.iter_mut().enumerate() {
+        for (row, value) in values.iter_mut().enumerate() {
+            *value = normal[(row, column)] as f32;
+        }
+    }
+    columns
+}
+
+fn add(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn sub(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+// faces degeneradas ficam com a normal nula
+fn normalize(a: [f64; 3]) -> [f64; 3] {
+    let length = dot(a, a).sqrt();
+    if length < 1e-12 {
+        return [0.0; 3];
+    }
+    [a[0] / length, a[1] / length, a[2] / length]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::glyph_registry::GlyphRegistry;
+
+    // cada aresta dos triângulos é percorrida uma vez em cada sentido: o
+    // sólido é fechado e as faces vizinhas têm a mesma orientação
+    fn assert_watertight(object: &Object, symbol: char) {
+        assert!(!object.triangle_index_buffer.is_empty(), "glifo {:?}: sem faces", symbol);
+        let position = |index: u32| object.vertex_buffer[index as usize].position.map(f32::to_bits);
+
+        let mut half_edges: HashMap<([u32; 3], [u32; 3]), usize> = HashMap::new();
+        for face in object.triangle_index_buffer.chunks(3) {
+            for i in 0..3 {
+                let edge = (position(face[i]), position(face[(i + 1) % 3]));
+                *half_edges.entry(edge).or_insert(0) += 1;
+            }
+        }
+
+        for (&(origin, destination), &count) in half_edges.iter() {
+            assert_eq!(count, 1, "glifo {:?}: aresta repetida", symbol);
+            assert_eq!(
+                half_edges.get(&(destination, origin)),
+                Some(&1),
+                "glifo {:?}: aresta sem a oposta",
+                symbol,
+            );
+        }
+    }
+
+    #[test]
+    fn extruded_glyphs_are_watertight() {
+        let mut registry = GlyphRegistry::new();
+        let symbols = ('A'..='Z').chain('0'..='9').chain("ÁÀÂÃÉÈÊÍÌÎÓÒÔÕÚÙÛÑÇ".chars());
+
+        for symbol in symbols {
+            let glyph = registry.get(symbol).unwrap();
+            let mut object = Object::new([0.0, 0.0, 0.0, 1.0]);
+            object.set_outline(&glyph.contours);
+            object.set_extrusion(Some(0.2));
+            assert_watertight(&object, symbol);
+
+            object.set_bevel(Some(Bevel {
+                depth: 0.05,
+                width: 0.03,
+                segments: 3,
+                profile: BevelProfile::Rounded,
+            }));
+            assert_watertight(&object, symbol);
+        }
+    }
+
+    #[test]
+    fn bevel_with_negative_extrusion_does_not_panic() {
+        let mut registry = GlyphRegistry::new();
+        let glyph = registry.get('A').unwrap();
+        let mut object = Object::new([0.0, 0.0, 0.0, 1.0]);
+        object.set_outline(&glyph.contours);
+        object.set_extrusion(Some(-0.2));
+        object.set_bevel(Some(Bevel {
+            depth: 0.05,
+            width: 0.03,
+            segments: 3,
+            profile: BevelProfile::Rounded,
+        }));
+        assert!(!object.triangle_index_buffer.is_empty());
+    }
+
+    // com escalas diferentes por eixo, a normal de cada face continua
+    // perpendicular às arestas da face transformada
+    #[test]
+    fn normals_stay_perpendicular_to_scaled_faces() {
+        let mut registry = GlyphRegistry::new();
+        let glyph = registry.get('A').unwrap();
+        let mut object = Object::new([0.0, 0.0, 0.0, 1.0]);
+        object.set_outline(&glyph.contours);
+        object.set_extrusion(Some(0.2));
+        object.set_scale([3.0, 0.5, 2.0]);
+        object.set_rotation(Quaternion::from_euler_angles(0.4, 0.7, 0.1));
+
+        let (model, normal) = object.model_matrix();
+        let apply = |position: [f32; 3]| {
+            let mut result = [0.0; 3];
+            for (row, value) in result.iter_mut().enumerate() {
+                *value = model[3][row] as f64
+                    + (0..3).map(|column| model[column][row] as f64 * position[column] as f64).sum::<f64>();
+            }
+            result
+        };
+
+        for face in object.triangle_index_buffer.chunks(3) {
+            let positions = [face[0], face[1], face[2]].map(|index| object.vertex_buffer[index as usize].position);
+            let a = positions[0].map(f64::from);
+            let (u, v) = (sub(positions[1].map(f64::from), a), sub(positions[2].map(f64::from), a));
+            let face_normal = [u[1] * v[2] - u[2] * v[1], u[2] * v[0] - u[0] * v[2], u[0] * v[1] - u[1] * v[0]];
+
+            let mut transformed = [0.0; 3];
+            for (row, value) in transformed.iter_mut().enumerate() {
+                *value = (0..3).map(|column| normal[column][row] as f64 * face_normal[column]).sum();
+            }
+            let transformed = normalize(transformed);
+
+            let origin = apply(positions[0]);
+            for position in &positions[1..] {
+                let edge = normalize(sub(apply(*position), origin));
+                assert!(dot(transformed, edge).abs() < 1e-3, "normal fora da perpendicular: {:?}", face);
+            }
+        }
+    }
+}
@@ -1,6 +1,27 @@
 struct VertexInput {
     [[location(0)]] position: vec3<f32>;
     [[location(1)]] color: vec3<f32>;
+    [[location(2)]] normal: vec3<f32>;
+};
+
+// estado de cada instância, em bits
+let SELECTED: u32 = 1u;
+let HOVERED: u32 = 2u;
+// destaca o estado mudando a cor dos vértices
+let HIGHLIGHT: u32 = 4u;
+
+// matriz de modelo do objeto, em colunas, a cor que tinge os vértices e o
+// estado do objeto na seleção
+struct InstanceInput {
+    [[location(3)]] model_0: vec4<f32>;
+    [[location(4)]] model_1: vec4<f32>;
+    [[location(5)]] model_2: vec4<f32>;
+    [[location(6)]] model_3: vec4<f32>;
+    [[location(7)]] color: vec4<f32>;
+    [[location(8)]] state: u32;
+    [[location(9)]] normal_0: vec3<f32>;
+    [[location(10)]] normal_1: vec3<f32>;
+    [[location(11)]] normal_2: vec3<f32>;
 };
 
 struct VertexOutput {
@@ -8,13 +29,69 @@ struct VertexOutput {
     [[location(0)]] color: vec3<f32>;
 };
 
+fn model_matrix(instance: InstanceInput) -> mat4x4<f32> {
+    return mat4x4<f32>(
+        instance.model_0,
+        instance.model_1,
+        instance.model_2,
+        instance.model_3
+    );
+}
+
+// vértice na vista, na cor dos vértices ou na de destaque
+fn transform(in: VertexInput, instance: InstanceInput) -> VertexOutput {
+    let position = model_matrix(instance) * vec4<f32>(in.position, 1.0);
+
+    var color = in.color * instance.color.rgb;
+    if ((instance.state & HIGHLIGHT) != 0u) {
+        if ((instance.state & SELECTED) != 0u) {
+            color = vec3<f32>(1.0, 0.0, 1.0);
+        }
+        if ((instance.state & HOVERED) != 0u) {
+            color = mix(color, vec3<f32>(0.0, 1.0, 1.0), vec3<f32>(0.5));
+        }
+    }
+
+    var out: VertexOutput;
+    out.color = color;
+    // a profundidade vai de 0 (z = 10, mais perto) a 1 (z = -10)
+    out.clip_position = vec4<f32>(position[0], position[1], 0.5 - position[2] * 0.05, 1.0); // front view
+    return out;
+}
+
+// linhas
 [[stage(vertex)]]
 fn vs_main(
     in: VertexInput,
+    instance: InstanceInput,
 ) -> VertexOutput {
-    var out: VertexOutput;
-    out.color = in.color;
-    out.clip_position = vec4<f32>(in.position[0], in.position[1], 0.0, 1.0); // front view
+    return transform(in, instance);
+}
+
+// faces, iluminadas por uma luz que vem de perto do observador
+[[stage(vertex)]]
+fn vs_faces(
+    in: VertexInput,
+    instance: InstanceInput,
+) -> VertexOutput {
+    let normal_matrix = mat3x3<f32>(instance.normal_0, instance.normal_1, instance.normal_2);
+    let normal = normalize(normal_matrix * in.normal);
+    let light = normalize(vec3<f32>(0.3, 0.4, 1.0));
+
+    var out = transform(in, instance);
+    out.color = out.color * (0.3 + 0.7 * abs(dot(normal, light)));
+    return out;
+}
+
+// linhas sobre as faces: mais escuras e um pouco à frente delas
+[[stage(vertex)]]
+fn vs_overlay(
+    in: VertexInput,
+    instance: InstanceInput,
+) -> VertexOutput {
+    var out = transform(in, instance);
+    out.color = out.color * 0.2;
+    out.clip_position[2] = out.clip_position[2] - 0.0005;
     return out;
 }
 
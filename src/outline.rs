@@ -0,0 +1,134 @@
+use crate::{
+    triangulation::triangulate,
+    types::{
+        FaceType,
+        Point,
+        Segment,
+    },
+};
+
+// limite de subdivisões para curvas muito tortuosas
+const MAX_DEPTH: u32 = 16;
+
+fn lerp(a: Point, b: Point, t: f64) -> Point {
+    [a[0] + (b[0] - a[0]) * t, a[1] + (b[1] - a[1]) * t]
+}
+
+// distância do ponto p à reta que passa por a e b
+fn distance_to_chord(p: Point, a: Point, b: Point) -> f64 {
+    let dx = b[0] - a[0];
+    let dy = b[1] - a[1];
+    let length = (dx * dx + dy * dy).sqrt();
+
+    if length == 0.0 {
+        return ((p[0] - a[0]).powi(2) + (p[1] - a[1]).powi(2)).sqrt();
+    }
+
+    ((p[0] - a[0]) * dy - (p[1] - a[1]) * dx).abs() / length
+}
+
+fn flatten_quadratic(
+    start: Point,
+    control: Point,
+    end: Point,
+    tolerance: f64,
+    depth: u32,
+    points: &mut Vec<Point>,
+) {
+    if depth >= MAX_DEPTH || distance_to_chord(control, start, end) <= tolerance {
+        points.push(end);
+        return;
+    }
+
+    // subdivisão de de Casteljau em t = 0.5
+    let a = lerp(start, control, 0.5);
+    let b = lerp(control, end, 0.5);
+    let middle = lerp(a, b, 0.5);
+
+    flatten_quadratic(start, a, middle, tolerance, depth + 1, points);
+    flatten_quadratic(middle, b, end, tolerance, depth + 1, points);
+}
+
+fn flatten_cubic(
+    start: Point,
+    first_control: Point,
+    second_control: Point,
+    end: Point,
+    tolerance: f64,
+    depth: u32,
+    points: &mut Vec<Point>,
+) {
+    let flatness = distance_to_chord(first_control, start, end)
+        .max(distance_to_chord(second_control, start, end));
+
+    if depth >= MAX_DEPTH || flatness <= tolerance {
+        points.push(end);
+        return;
+    }
+
+    let a = lerp(start, first_control, 0.5);
+    let b = lerp(first_control, second_control, 0.5);
+    let c = lerp(second_control, end, 0.5);
+    let ab = lerp(a, b, 0.5);
+    let bc = lerp(b, c, 0.5);
+    let middle = lerp(ab, bc, 0.5);
+
+    flatten_cubic(start, a, ab, middle, tolerance, depth + 1, points);
+    flatten_cubic(middle, bc, c, end, tolerance, depth + 1, points);
+}
+
+fn end_point(segment: &Segment) -> Point {
+    match *segment {
+        Segment::Line(end) => end,
+        Segment::Quadratic(_, end) => end,
+        Segment::Cubic(_, _, end) => end,
+    }
+}
+
+// converte um contorno com curvas em uma poligonal fechada, subdividindo
+// cada curva até que ela se afaste no máximo `tolerance` da poligonal
+pub fn flatten(contour: &[Segment], tolerance: f64) -> Vec<Point> {
+    let mut points: Vec<Point> = Vec::new();
+
+    let mut current = match contour.last() {
+        Some(segment) => end_point(segment),
+        None => return points,
+    };
+
+    let tolerance = tolerance.max(f64::EPSILON);
+
+    for segment in contour {
+        match *segment {
+            Segment::Line(end) => {
+                points.push(end);
+            }
+            Segment::Quadratic(control, end) => {
+                flatten_quadratic(current, control, end, tolerance, 0, &mut points);
+            }
+            Segment::Cubic(first_control, second_control, end) => {
+                flatten_cubic(
+                    current,
+                    first_control,
+                    second_control,
+                    end,
+                    tolerance,
+                    0,
+                    &mut points,
+                );
+            }
+        }
+        current = end_point(segment);
+    }
+
+    points
+}
+
+// aproxima os contornos por poligonais e os triangula
+pub fn tessellate(contours: &[Vec<Segment>], tolerance: f64) -> Vec<FaceType> {
+    let polygons: Vec<Vec<Point>> = contours
+        .iter()
+        .map(|contour| flatten(contour, tolerance))
+        .collect();
+
+    triangulate(&polygons)
+}
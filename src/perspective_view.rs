@@ -1,7 +1,12 @@
 use std::borrow::Cow;
 
 use crate::{
-    constants::VERTICES_BUFFER_LAYOUT,
+    constants::DEPTH_FORMAT,
+    pipelines::{
+        Pipelines,
+        RenderMode,
+    },
+    scene::Scene,
     texture::Texture,
 };
 
@@ -11,7 +16,8 @@ pub struct PerspectiveView {
     height: u32,
     texture: Texture,
     texture_id: egui::TextureId,
-    render_pipeline: wgpu::RenderPipeline,
+    depth_texture: Texture,
+    pipelines: Pipelines,
 }
 
 impl PerspectiveView {
@@ -33,7 +39,7 @@ impl PerspectiveView {
         let dimensions = (width, height);
 
         let texture = Texture::new(
-            &device,
+            device,
             dimensions,
             Some(&(label.to_string() + "-texture")),
             wgpu::TextureFormat::Rgba8UnormSrgb,
@@ -46,41 +52,15 @@ impl PerspectiveView {
             wgpu::FilterMode::Linear,
         );
 
-        let render_pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
-            label: Some(&(label.to_string() + "-pipeline-layout")),
-            bind_group_layouts: &[],
-            push_constant_ranges: &[],
-        });
-        
-        let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some(&(label.to_string() + "-render-pipeline")),
-            layout: Some(&render_pipeline_layout),
-            vertex: wgpu::VertexState {
-                module: &shader,
-                entry_point: "vs_main",
-                buffers: &[VERTICES_BUFFER_LAYOUT],
-            },
-            fragment: Some(wgpu::FragmentState {
-                module: &shader,
-                entry_point: "fs_main",
-                targets: &[wgpu::ColorTargetState {
-                    format: texture.format,
-                    blend: Some(wgpu::BlendState::REPLACE),
-                    write_mask: wgpu::ColorWrites::ALL,
-                }],
-            }),
-            primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::LineList,
-                strip_index_format: None,
-                front_face: wgpu::FrontFace::Ccw,
-                cull_mode: Some(wgpu::Face::Back),
-                polygon_mode: wgpu::PolygonMode::Line,
-                conservative: false,
-                clamp_depth: false,
-            },
-            depth_stencil: None,
-            multisample: wgpu::MultisampleState::default(),
-        });
+        let depth_texture = Texture::new(
+            device,
+            dimensions,
+            Some(&(label.to_string() + "-depth-texture")),
+            DEPTH_FORMAT,
+            false,
+        );
+
+        let pipelines = Pipelines::new(device, label, &shader, texture.format);
 
         Self {
             label: String::from(label),
@@ -88,7 +68,8 @@ impl PerspectiveView {
             height,
             texture,
             texture_id,
-            render_pipeline,
+            depth_texture,
+            pipelines,
         }
     }
 
@@ -117,21 +98,32 @@ impl PerspectiveView {
             &self.texture.texture,
             wgpu::FilterMode::Linear,
         );
-    }
 
-    pub fn show(&mut self, ui: &mut egui::Ui, _device: &wgpu::Device) {
-        ui.image(
-            self.texture_id,
-            (self.width as f32, self.height as f32),
+        self.depth_texture = Texture::new(
+            device,
+            dimensions,
+            Some(&(self.label.to_string() + "-depth-texture")),
+            DEPTH_FORMAT,
+            false,
         );
     }
 
+    // mostra a vista e devolve a resposta da imagem ao mouse
+    pub fn show(&mut self, ui: &mut egui::Ui, _device: &wgpu::Device) -> egui::Response {
+        ui.add(
+            egui::Image::new(
+                self.texture_id,
+                (self.width as f32, self.height as f32),
+            )
+            .sense(egui::Sense::click()),
+        )
+    }
+
     pub fn render(
         &mut self,
         encoder: &mut wgpu::CommandEncoder,
-        vertex_buffer_slice: wgpu::BufferSlice,
-        index_buffer_slice: wgpu::BufferSlice,
-        index_buffer_len: u32,
+        scene: &Scene,
+        render_mode: RenderMode,
     ) {
         let label = self.label.to_string() + "-render-pass";
 
@@ -150,12 +142,16 @@ impl PerspectiveView {
                     store: true,
                 },
             }],
-            depth_stencil_attachment: None,
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                view: &self.depth_texture.view,
+                depth_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(1.0),
+                    store: true,
+                }),
+                stencil_ops: None,
+            }),
         });
 
-        render_pass.set_pipeline(&self.render_pipeline);
-        render_pass.set_vertex_buffer(0, vertex_buffer_slice);
-        render_pass.set_index_buffer(index_buffer_slice, wgpu::IndexFormat::Uint32);
-        render_pass.draw_indexed(0..index_buffer_len, 0, 0..1);
+        self.pipelines.draw(&mut render_pass, scene, render_mode);
     }
 }
\ No newline at end of file
@@ -0,0 +1,119 @@
+use crate::{
+    constants::{
+        DEPTH_FORMAT,
+        INSTANCES_BUFFER_LAYOUT,
+        VERTICES_BUFFER_LAYOUT,
+    },
+    scene::{
+        Meshes,
+        Scene,
+    },
+};
+
+// forma de desenhar os objetos nas vistas
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RenderMode {
+    Wireframe,
+    Solid,
+    // faces com as linhas por cima
+    SolidWireframe,
+}
+
+// pipelines de uma vista: linhas, faces e linhas sobre as faces, todas
+// com o mesmo shader e o mesmo teste de profundidade
+pub struct Pipelines {
+    lines: wgpu::RenderPipeline,
+    faces: wgpu::RenderPipeline,
+    overlay: wgpu::RenderPipeline,
+}
+
+impl Pipelines {
+    pub fn new(
+        device: &wgpu::Device,
+        label: &str,
+        shader: &wgpu::ShaderModule,
+        format: wgpu::TextureFormat,
+    ) -> Self {
+        let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some(&(label.to_string() + "-pipeline-layout")),
+            bind_group_layouts: &[],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = |name: &str, entry_point: &str, topology: wgpu::PrimitiveTopology| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some(&format!("{}-{}-render-pipeline", label, name)),
+                layout: Some(&layout),
+                vertex: wgpu::VertexState {
+                    module: shader,
+                    entry_point,
+                    buffers: &[VERTICES_BUFFER_LAYOUT, INSTANCES_BUFFER_LAYOUT],
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: shader,
+                    entry_point: "fs_main",
+                    targets: &[wgpu::ColorTargetState {
+                        format,
+                        blend: Some(wgpu::BlendState::REPLACE),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    }],
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology,
+                    strip_index_format: None,
+                    front_face: wgpu::FrontFace::Ccw,
+                    // as vistas laterais espelham a cena, então as duas
+                    // faces de cada triângulo são desenhadas
+                    cull_mode: None,
+                    polygon_mode: wgpu::PolygonMode::Fill,
+                    conservative: false,
+                    clamp_depth: false,
+                },
+                depth_stencil: Some(wgpu::DepthStencilState {
+                    format: DEPTH_FORMAT,
+                    depth_write_enabled: true,
+                    depth_compare: wgpu::CompareFunction::LessEqual,
+                    stencil: wgpu::StencilState::default(),
+                    bias: wgpu::DepthBiasState::default(),
+                }),
+                multisample: wgpu::MultisampleState::default(),
+            })
+        };
+
+        Self {
+            lines: pipeline("lines", "vs_main", wgpu::PrimitiveTopology::LineList),
+            faces: pipeline("faces", "vs_faces", wgpu::PrimitiveTopology::TriangleList),
+            overlay: pipeline("overlay", "vs_overlay", wgpu::PrimitiveTopology::LineList),
+        }
+    }
+
+    // o cursor e os contornos de destaque, que não têm faces, são sempre
+    // desenhados como linhas
+    pub fn draw<'a>(
+        &'a self,
+        render_pass: &mut wgpu::RenderPass<'a>,
+        scene: &'a Scene,
+        render_mode: RenderMode,
+    ) {
+        match render_mode {
+            RenderMode::Wireframe => {
+                render_pass.set_pipeline(&self.lines);
+                scene.draw_lines(render_pass, Meshes::All);
+            }
+            RenderMode::Solid => {
+                render_pass.set_pipeline(&self.faces);
+                scene.draw_triangles(render_pass);
+                render_pass.set_pipeline(&self.lines);
+                scene.draw_lines(render_pass, Meshes::WithoutFaces);
+            }
+            RenderMode::SolidWireframe => {
+                render_pass.set_pipeline(&self.faces);
+                scene.draw_triangles(render_pass);
+                render_pass.set_pipeline(&self.overlay);
+                scene.draw_lines(render_pass, Meshes::WithFaces);
+                render_pass.set_pipeline(&self.lines);
+                scene.draw_lines(render_pass, Meshes::WithoutFaces);
+            }
+        }
+    }
+}
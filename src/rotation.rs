@@ -0,0 +1,183 @@
+use nalgebra::{
+    Unit,
+    Vector3,
+};
+
+use crate::types::{
+    Quaternion,
+    Rotation,
+};
+
+// ordem em que as rotações em torno dos eixos fixos do mundo são aplicadas
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RotationOrder {
+    Xyz,
+    Xzy,
+    Yxz,
+    Yzx,
+    Zxy,
+    Zyx,
+}
+
+impl RotationOrder {
+    pub const ALL: [RotationOrder; 6] = [
+        RotationOrder::Xyz,
+        RotationOrder::Xzy,
+        RotationOrder::Yxz,
+        RotationOrder::Yzx,
+        RotationOrder::Zxy,
+        RotationOrder::Zyx,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            RotationOrder::Xyz => "XYZ",
+            RotationOrder::Xzy => "XZY",
+            RotationOrder::Yxz => "YXZ",
+            RotationOrder::Yzx => "YZX",
+            RotationOrder::Zxy => "ZXY",
+            RotationOrder::Zyx => "ZYX",
+        }
+    }
+
+    // índices dos eixos na ordem de aplicação
+    fn axes(&self) -> [usize; 3] {
+        match self {
+            RotationOrder::Xyz => [0, 1, 2],
+            RotationOrder::Xzy => [0, 2, 1],
+            RotationOrder::Yxz => [1, 0, 2],
+            RotationOrder::Yzx => [1, 2, 0],
+            RotationOrder::Zxy => [2, 0, 1],
+            RotationOrder::Zyx => [2, 1, 0],
+        }
+    }
+}
+
+// eixos em torno dos quais uma rotação incremental é feita
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Space {
+    // eixos fixos da cena
+    World,
+    // eixos do objeto, que giram junto com ele
+    Local,
+}
+
+fn axis(index: usize) -> Unit<Vector3<f64>> {
+    match index {
+        0 => Vector3::x_axis(),
+        1 => Vector3::y_axis(),
+        _ => Vector3::z_axis(),
+    }
+}
+
+// rotação equivalente aos ângulos de Euler (em graus, indexados por eixo)
+// aplicados na ordem indicada
+pub fn from_euler(angles: Rotation, order: RotationOrder) -> Quaternion {
+    order
+        .axes()
+        .iter()
+        .fold(Quaternion::identity(), |rotation, &index| {
+            Quaternion::from_axis_angle(&axis(index), angles[index].to_radians()) * rotation
+        })
+}
+
+// ângulos de Euler (em graus, indexados por eixo) que reproduzem a rotação
+// na ordem indicada; no bloqueio de cardan o último ângulo fica zerado
+pub fn to_euler(rotation: &Quaternion, order: RotationOrder) -> Rotation {
+    let [i, j, k] = order.axes();
+    let matrix = rotation.to_rotation_matrix();
+    let m = |row: usize, column: usize| matrix[(row, column)];
+
+    // sinal da permutação dos eixos (ordens cíclicas são positivas)
+    let sign = if (j + 3 - i) % 3 == 1 { 1.0 } else { -1.0 };
+
+    let mut angles = [0.0; 3];
+    let sin = (-sign * m(k, i)).clamp(-1.0, 1.0);
+    angles[j] = sin.asin();
+
+    if sin.abs() < 1.0 - 1e-9 {
+        angles[i] = (sign * m(k, j)).atan2(m(k, k));
+        angles[k] = (sign * m(j, i)).atan2(m(i, i));
+    } else {
+        angles[i] = (-sign * m(j, k)).atan2(m(j, j));
+        angles[k] = 0.0;
+    }
+
+    angles.map(f64::to_degrees)
+}
+
+// rotação de um ângulo (em graus) em torno de um eixo qualquer; None se o
+// eixo for nulo
+pub fn from_axis_angle(direction: [f64; 3], angle: f64) -> Option<Quaternion> {
+    let direction = Unit::try_new(Vector3::from(direction), 1e-12)?;
+    Some(Quaternion::from_axis_angle(&direction, angle.to_radians()))
+}
+
+// eixo e ângulo (em graus) da rotação
+pub fn to_axis_angle(rotation: &Quaternion) -> ([f64; 3], f64) {
+    match rotation.axis_angle() {
+        Some((direction, angle)) => ([direction.x, direction.y, direction.z], angle.to_degrees()),
+        None => ([0.0, 0.0, 1.0], 0.0),
+    }
+}
+
+// aplica uma rotação extra de um ângulo (em graus) em torno de um dos
+// eixos do mundo ou do próprio objeto
+pub fn rotate(rotation: &Quaternion, index: usize, angle: f64, space: Space) -> Quaternion {
+    let step = Quaternion::from_axis_angle(&axis(index), angle.to_radians());
+    match space {
+        Space::World => step * rotation,
+        Space::Local => rotation * step,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn euler_angles_round_trip_in_every_order() {
+        let samples = [
+            [30.0, -45.0, 60.0],
+            [-120.0, 10.0, 170.0],
+            [5.0, 89.0, -30.0],
+            [0.0, 0.0, 0.0],
+        ];
+
+        for order in RotationOrder::ALL {
+            for angles in samples {
+                let rotation = from_euler(angles, order);
+                let back = from_euler(to_euler(&rotation, order), order);
+                assert!(rotation.angle_to(&back) < 1e-9, "{:?} {:?}", order, angles);
+            }
+
+            // bloqueio de cardan: o ângulo do meio em 90°
+            let mut angles = [20.0, 20.0, 20.0];
+            angles[order.axes()[1]] = 90.0;
+            let rotation = from_euler(angles, order);
+            let back = from_euler(to_euler(&rotation, order), order);
+            assert!(rotation.angle_to(&back) < 1e-6, "{:?} {:?}", order, angles);
+        }
+    }
+
+    #[test]
+    fn xyz_order_matches_the_previous_matrices() {
+        // antes, os vértices giravam em x, depois em y e por fim em z
+        let rotation = from_euler([90.0, 0.0, 90.0], RotationOrder::Xyz);
+        let point = rotation * nalgebra::Point3::new(0.0, 1.0, 0.0);
+        assert!((point - nalgebra::Point3::new(0.0, 0.0, 1.0)).norm() < 1e-9);
+    }
+
+    #[test]
+    fn local_and_world_rotations_differ_after_a_turn() {
+        let rotation = from_euler([0.0, 0.0, 90.0], RotationOrder::Xyz);
+
+        let world = rotate(&rotation, 0, 90.0, Space::World);
+        let local = rotate(&rotation, 0, 90.0, Space::Local);
+
+        // o x local aponta para o y do mundo
+        let expected = Quaternion::from_axis_angle(&Vector3::y_axis(), 90f64.to_radians()) * rotation;
+        assert!(local.angle_to(&expected) < 1e-9);
+        assert!(world.angle_to(&local) > 1e-3);
+    }
+}
@@ -0,0 +1,474 @@
+use std::{
+    collections::{
+        hash_map::DefaultHasher,
+        HashMap,
+        HashSet,
+    },
+    hash::{
+        Hash,
+        Hasher,
+    },
+    mem::size_of,
+    ops::Range,
+};
+
+use bytemuck::Zeroable;
+
+use crate::types::{
+    ShaderInstance,
+    ShaderVertex,
+};
+
+// capacidade inicial de cada buffer, em elementos
+const INITIAL_CAPACITY: usize = 1024;
+
+// chave da única faixa do buffer de instâncias
+const INSTANCES: u64 = 0;
+
+// faixa de um buffer reservada a uma chave, em elementos
+#[derive(Debug, Clone, Copy)]
+struct Allocation {
+    start: usize,
+    len: usize,
+    capacity: usize,
+}
+
+// buffer da GPU que persiste entre as atualizações, dividido em uma faixa
+// por chave; quando algo não cabe, as faixas em uso são copiadas juntas
+// para um buffer novo, que dobra de tamanho até ter espaço
+struct Arena {
+    label: &'static str,
+    usage: wgpu::BufferUsages,
+    // tamanho de cada elemento, em bytes
+    stride: usize,
+    buffer: wgpu::Buffer,
+    capacity: usize,
+    // fim da última faixa reservada
+    end: usize,
+    allocations: HashMap<u64, Allocation>,
+}
+
+impl Arena {
+    fn new(
+        device: &wgpu::Device,
+        label: &'static str,
+        usage: wgpu::BufferUsages,
+        stride: usize,
+    ) -> Self {
+        let usage = usage | wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::COPY_SRC;
+
+        Self {
+            label,
+            usage,
+            stride,
+            buffer: device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some(label),
+                size: (INITIAL_CAPACITY * stride) as wgpu::BufferAddress,
+                usage,
+                mapped_at_creation: false,
+            }),
+            capacity: INITIAL_CAPACITY,
+            end: 0,
+            allocations: HashMap::new(),
+        }
+    }
+
+    fn get(&self, key: u64) -> Option<Allocation> {
+        self.allocations.get(&key).copied()
+    }
+
+    // escreve os dados na faixa da chave, que muda para o fim do buffer
+    // quando não cabem nela
+    fn write(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, key: u64, data: &[u8]) {
+        let len = data.len() / self.stride;
+
+        let allocation = match self.allocations.get(&key) {
+            Some(allocation) if allocation.capacity >= len => Allocation {
+                len,
+                ..*allocation
+            },
+            _ => {
+                self.allocations.remove(&key);
+                if self.end + len > self.capacity {
+                    self.repack(device, queue, len);
+                }
+                self.end += len;
+                Allocation {
+                    start: self.end - len,
+                    len,
+                    capacity: len,
+                }
+            }
+        };
+        self.allocations.insert(key, allocation);
+
+        if !data.is_empty() {
+            queue.write_buffer(
+                &self.buffer,
+                (allocation.start * self.stride) as wgpu::BufferAddress,
+                data,
+            );
+        }
+    }
+
+    // reescreve um único elemento de uma faixa já reservada
+    fn write_element(&self, queue: &wgpu::Queue, key: u64, index: usize, data: &[u8]) {
+        if let Some(allocation) = self.allocations.get(&key) {
+            queue.write_buffer(
+                &self.buffer,
+                ((allocation.start + index) * self.stride) as wgpu::BufferAddress,
+                data,
+            );
+        }
+    }
+
+    fn remove(&mut self, key: u64) {
+        self.allocations.remove(&key);
+    }
+
+    // copia as faixas em uso, sem os espaços deixados entre elas, para um
+    // buffer novo com espaço para mais `extra` elementos
+    fn repack(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, extra: usize) {
+        let used: usize = self.allocations.values().map(|allocation| allocation.len).sum();
+        let mut capacity = self.capacity;
+        while capacity < used + extra {
+            capacity *= 2;
+        }
+
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some(self.label),
+            size: (capacity * self.stride) as wgpu::BufferAddress,
+            usage: self.usage,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("repack-encoder"),
+        });
+
+        let mut allocations: Vec<&mut Allocation> = self.allocations.values_mut().collect();
+        allocations.sort_by_key(|allocation| allocation.start);
+
+        let mut end = 0;
+        for allocation in allocations {
+            if allocation.len > 0 {
+                encoder.copy_buffer_to_buffer(
+                    &self.buffer,
+                    (allocation.start * self.stride) as wgpu::BufferAddress,
+                    &buffer,
+                    (end * self.stride) as wgpu::BufferAddress,
+                    (allocation.len * self.stride) as wgpu::BufferAddress,
+                );
+            }
+            allocation.start = end;
+            allocation.capacity = allocation.len;
+            end += allocation.len;
+        }
+
+        queue.submit(Some(encoder.finish()));
+
+        self.buffer = buffer;
+        self.capacity = capacity;
+        self.end = end;
+    }
+}
+
+// chave de uma malha pelo seu conteúdo: glifos iguais, com os mesmos
+// parâmetros, geram a mesma chave. Malhas diferentes também podem gerá-la,
+// então a chave só é usada depois de comparar o conteúdo
+fn mesh_key(vertices: &[ShaderVertex], lines_indices: &[u32], triangles_indices: &[u32]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    bytemuck::cast_slice::<ShaderVertex, u8>(vertices).hash(&mut hasher);
+    lines_indices.hash(&mut hasher);
+    triangles_indices.hash(&mut hasher);
+    hasher.finish()
+}
+
+// malhas cujas linhas são desenhadas
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Meshes {
+    All,
+    // só as que têm faces, como os glifos
+    WithFaces,
+    // só as feitas apenas de linhas, como o cursor e os contornos de destaque
+    WithoutFaces,
+}
+
+// cópia de uma malha enviada à GPU, para distinguir malhas diferentes com
+// a mesma chave
+struct Mesh {
+    vertices: Vec<ShaderVertex>,
+    lines_indices: Vec<u32>,
+    triangles_indices: Vec<u32>,
+    // quantos objetos usam a malha
+    users: usize,
+}
+
+impl Mesh {
+    fn matches(&self, vertices: &[ShaderVertex], lines_indices: &[u32], triangles_indices: &[u32]) -> bool {
+        bytemuck::cast_slice::<ShaderVertex, u8>(&self.vertices) == bytemuck::cast_slice::<ShaderVertex, u8>(vertices)
+            && self.lines_indices == lines_indices
+            && self.triangles_indices == triangles_indices
+    }
+}
+
+// objeto da cena: a malha que usa e os dados da sua instância
+struct Instance {
+    mesh: u64,
+    data: ShaderInstance,
+}
+
+// malhas e instâncias já enviadas à GPU, compartilhadas pelas quatro
+// vistas; cada malha diferente é enviada uma única vez e os objetos que a
+// usam são desenhados juntos, como instâncias
+pub struct Scene {
+    // faixas por malha
+    vertices: Arena,
+    lines_indices: Arena,
+    triangles_indices: Arena,
+    meshes: HashMap<u64, Mesh>,
+    // instâncias agrupadas por malha, em uma única faixa
+    instances: Arena,
+    objects: HashMap<usize, Instance>,
+    // objetos na ordem em que estão no buffer de instâncias
+    instance_order: Vec<usize>,
+    // objetos cuja instância mudou desde o último envio
+    changed: HashSet<usize>,
+    // malhas a desenhar e as instâncias de cada uma
+    groups: Vec<(u64, Range<u32>)>,
+}
+
+impl Scene {
+    pub fn new(device: &wgpu::Device) -> Self {
+        Self {
+            vertices: Arena::new(
+                device,
+                "vertex-buffer",
+                wgpu::BufferUsages::VERTEX,
+                size_of::<ShaderVertex>(),
+            ),
+            lines_indices: Arena::new(
+                device,
+                "line-indices-buffer",
+                wgpu::BufferUsages::INDEX,
+                size_of::<u32>(),
+            ),
+            triangles_indices: Arena::new(
+                device,
+                "triangle-indices-buffer",
+                wgpu::BufferUsages::INDEX,
+                size_of::<u32>(),
+            ),
+            meshes: HashMap::new(),
+            instances: Arena::new(
+                device,
+                "instance-buffer",
+                wgpu::BufferUsages::VERTEX,
+                size_of::<ShaderInstance>(),
+            ),
+            objects: HashMap::new(),
+            instance_order: Vec::new(),
+            changed: HashSet::new(),
+            groups: Vec::new(),
+        }
+    }
+
+    pub fn contains(&self, id: usize) -> bool {
+        self.objects.contains_key(&id)
+    }
+
+    // associa o objeto à malha com estes vértices e índices (locais aos
+    // vértices da própria malha), enviando-a só se ainda não estiver na GPU
+    pub fn set_mesh(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        id: usize,
+        vertices: &[ShaderVertex],
+        lines_indices: &[u32],
+        triangles_indices: &[u32],
+    ) {
+        // em uma colisão, a malha fica na próxima chave livre ou com o
+        // mesmo conteúdo
+        let mut key = mesh_key(vertices, lines_indices, triangles_indices);
+        while let Some(mesh) = self.meshes.get(&key) {
+            if mesh.matches(vertices, lines_indices, triangles_indices) {
+                break;
+            }
+            key = key.wrapping_add(1);
+        }
+
+        match self.objects.get(&id) {
+            Some(object) if object.mesh == key => return,
+            Some(object) => {
+                let previous = object.mesh;
+                self.release_mesh(previous);
+            }
+            None => {}
+        }
+
+        if !self.meshes.contains_key(&key) {
+            self.vertices.write(device, queue, key, bytemuck::cast_slice(vertices));
+            self.lines_indices.write(device, queue, key, bytemuck::cast_slice(lines_indices));
+            self.triangles_indices.write(device, queue, key, bytemuck::cast_slice(triangles_indices));
+        }
+        self.meshes
+            .entry(key)
+            .or_insert_with(|| Mesh {
+                vertices: vertices.to_vec(),
+                lines_indices: lines_indices.to_vec(),
+                triangles_indices: triangles_indices.to_vec(),
+                users: 0,
+            })
+            .users += 1;
+
+        self.objects
+            .entry(id)
+            .and_modify(|object| object.mesh = key)
+            .or_insert(Instance {
+                mesh: key,
+                data: ShaderInstance::zeroed(),
+            });
+        self.changed.insert(id);
+    }
+
+    // troca os dados da instância do objeto (já associado a uma malha)
+    pub fn set_instance(&mut self, id: usize, data: ShaderInstance) {
+        if let Some(object) = self.objects.get_mut(&id) {
+            object.data = data;
+            self.changed.insert(id);
+        }
+    }
+
+    fn release_mesh(&mut self, key: u64) {
+        if let Some(mesh) = self.meshes.get_mut(&key) {
+            mesh.users -= 1;
+            if mesh.users == 0 {
+                self.meshes.remove(&key);
+                self.vertices.remove(key);
+                self.lines_indices.remove(key);
+                self.triangles_indices.remove(key);
+            }
+        }
+    }
+
+    // objetos a desenhar, na ordem; os demais são descartados. As
+    // instâncias são reescritas por inteiro só quando os grupos mudam;
+    // senão, apenas as que mudaram
+    pub fn update_order(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, order: &[usize]) {
+        let kept: HashSet<&usize> = order.iter().collect();
+        let removed: Vec<usize> = self.objects
+            .keys()
+            .filter(|id| !kept.contains(id))
+            .copied()
+            .collect();
+        for id in removed {
+            if let Some(object) = self.objects.remove(&id) {
+                self.release_mesh(object.mesh);
+            }
+        }
+
+        // agrupa os objetos pela malha, na ordem em que cada malha aparece
+        let mut groups: Vec<(u64, Vec<usize>)> = Vec::new();
+        let mut group_of: HashMap<u64, usize> = HashMap::new();
+        for id in order.iter() {
+            let mesh = match self.objects.get(id) {
+                Some(object) => object.mesh,
+                None => continue,
+            };
+            let group = *group_of.entry(mesh).or_insert_with(|| {
+                groups.push((mesh, Vec::new()));
+                groups.len() - 1
+            });
+            groups[group].1.push(*id);
+        }
+
+        let instance_order: Vec<usize> = groups
+            .iter()
+            .flat_map(|(_, ids)| ids.iter().copied())
+            .collect();
+
+        if instance_order != self.instance_order {
+            let data: Vec<ShaderInstance> = instance_order
+                .iter()
+                .map(|id| self.objects[id].data)
+                .collect();
+            self.instances.write(device, queue, INSTANCES, bytemuck::cast_slice(&data));
+        } else {
+            for (index, id) in instance_order.iter().enumerate() {
+                if self.changed.contains(id) {
+                    self.instances.write_element(
+                        queue,
+                        INSTANCES,
+                        index,
+                        bytemuck::bytes_of(&self.objects[id].data),
+                    );
+                }
+            }
+        }
+        self.changed.clear();
+
+        let mut first = 0;
+        self.groups = groups
+            .iter()
+            .map(|(mesh, ids)| {
+                first += ids.len() as u32;
+                (*mesh, first - ids.len() as u32..first)
+            })
+            .collect();
+        self.instance_order = instance_order;
+    }
+
+    pub fn draw_lines<'a>(&'a self, render_pass: &mut wgpu::RenderPass<'a>, meshes: Meshes) {
+        self.draw(render_pass, &self.lines_indices, |mesh| {
+            let faces = self.triangles_indices.get(mesh).is_some_and(|indices| indices.len > 0);
+            match meshes {
+                Meshes::All => true,
+                Meshes::WithFaces => faces,
+                Meshes::WithoutFaces => !faces,
+            }
+        });
+    }
+
+    pub fn draw_triangles<'a>(&'a self, render_pass: &mut wgpu::RenderPass<'a>) {
+        self.draw(render_pass, &self.triangles_indices, |_| true);
+    }
+
+    // desenha, com os índices do buffer indicado, as malhas aceitas pelo
+    // filtro e as suas instâncias
+    fn draw<'a>(
+        &'a self,
+        render_pass: &mut wgpu::RenderPass<'a>,
+        indices: &'a Arena,
+        filter: impl Fn(u64) -> bool,
+    ) {
+        let instances = match self.instances.get(INSTANCES) {
+            Some(instances) => instances,
+            None => return,
+        };
+
+        render_pass.set_vertex_buffer(0, self.vertices.buffer.slice(..));
+        render_pass.set_vertex_buffer(1, self.instances.buffer.slice(..));
+        render_pass.set_index_buffer(indices.buffer.slice(..), wgpu::IndexFormat::Uint32);
+
+        for (mesh, range) in self.groups.iter() {
+            if !filter(*mesh) {
+                continue;
+            }
+            if let (Some(vertices), Some(mesh_indices)) = (
+                self.vertices.get(*mesh),
+                indices.get(*mesh),
+            ) {
+                if mesh_indices.len == 0 {
+                    continue;
+                }
+                let first = instances.start as u32;
+                render_pass.draw_indexed(
+                    mesh_indices.start as u32..(mesh_indices.start + mesh_indices.len) as u32,
+                    vertices.start as i32,
+                    first + range.start..first + range.end,
+                );
+            }
+        }
+    }
+}
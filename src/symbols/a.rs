@@ -0,0 +1,29 @@
+use crate::types::Segment::{
+    self,
+    Line,
+};
+
+const SA_CONTOUR_A: [Segment; 10] = [
+    Line([-0.1, -0.5]),
+    Line([-0.1, -0.1]),
+    Line([ 0.1, -0.1]),
+    Line([ 0.1, -0.5]),
+    Line([ 0.3, -0.5]),
+    Line([ 0.3,  0.3]),
+    Line([ 0.1,  0.5]),
+    Line([-0.1,  0.5]),
+    Line([-0.3,  0.3]),
+    Line([-0.3, -0.5]),
+];
+
+const SA_CONTOUR_B: [Segment; 4] = [
+    Line([ 0.1,  0.3]),
+    Line([ 0.1,  0.1]),
+    Line([-0.1,  0.1]),
+    Line([-0.1,  0.3]),
+];
+
+pub const SA: [&[Segment]; 2] = [
+    &SA_CONTOUR_A,
+    &SA_CONTOUR_B,
+];
\ No newline at end of file
@@ -0,0 +1,39 @@
+use crate::types::Segment::{
+    self,
+    Cubic,
+    Line,
+};
+
+const SB_CONTOUR_A: [Segment; 9] = [
+    Line([0.1, -0.5]),
+    Cubic([0.2105, -0.5], [0.3, -0.4105], [0.3, -0.3]),
+    Line([0.3, -0.1]),
+    Cubic([0.2448, -0.1], [0.2, -0.0552], [0.2, 0.0]),
+    Cubic([0.2, 0.0552], [0.2448, 0.1], [0.3, 0.1]),
+    Line([0.3, 0.3]),
+    Cubic([0.3, 0.4105], [0.2105, 0.5], [0.1, 0.5]),
+    Line([-0.3, 0.5]),
+    Line([-0.3, -0.5]),
+];
+
+const SB_CONTOUR_B: [Segment; 5] = [
+    Line([-0.1, -0.1]),
+    Line([0.0, -0.1]),
+    Cubic([0.0552, -0.1], [0.1, -0.1448], [0.1, -0.2]),
+    Cubic([0.1, -0.2552], [0.0552, -0.3], [0.0, -0.3]),
+    Line([-0.1, -0.3]),
+];
+
+const SB_CONTOUR_C: [Segment; 5] = [
+    Line([-0.1, 0.3]),
+    Line([0.0, 0.3]),
+    Cubic([0.0552, 0.3], [0.1, 0.2552], [0.1, 0.2]),
+    Cubic([0.1, 0.1448], [0.0552, 0.1], [0.0, 0.1]),
+    Line([-0.1, 0.1]),
+];
+
+pub const SB: [&[Segment]; 3] = [
+    &SB_CONTOUR_A,
+    &SB_CONTOUR_B,
+    &SB_CONTOUR_C,
+];
\ No newline at end of file
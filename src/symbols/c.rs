@@ -0,0 +1,24 @@
+use crate::types::Segment::{
+    self,
+    Cubic,
+    Line,
+};
+
+const SC_CONTOUR_A: [Segment; 12] = [
+    Cubic([0.3, 0.3657], [0.1657, 0.5], [0.0, 0.5]),
+    Cubic([-0.1657, 0.5], [-0.3, 0.3657], [-0.3, 0.2]),
+    Line([-0.3, -0.2]),
+    Cubic([-0.3, -0.3657], [-0.1657, -0.5], [0.0, -0.5]),
+    Cubic([0.1657, -0.5], [0.3, -0.3657], [0.3, -0.2]),
+    Line([0.1, -0.2]),
+    Cubic([0.1, -0.2552], [0.0552, -0.3], [0.0, -0.3]),
+    Cubic([-0.0552, -0.3], [-0.1, -0.2552], [-0.1, -0.2]),
+    Line([-0.1, 0.2]),
+    Cubic([-0.1, 0.2552], [-0.0552, 0.3], [0.0, 0.3]),
+    Cubic([0.0552, 0.3], [0.1, 0.2552], [0.1, 0.2]),
+    Line([0.3, 0.2]),
+];
+
+pub const SC: [&[Segment]; 1] = [
+    &SC_CONTOUR_A,
+];
\ No newline at end of file
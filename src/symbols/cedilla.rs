@@ -0,0 +1,21 @@
+use crate::types::Segment::{
+    self,
+    Line,
+};
+
+const SCEDILLA_CONTOUR_A: [Segment; 10] = [
+    Line([-0.05,  -0.2]),
+    Line([ 0.07,  -0.2]),
+    Line([ 0.07, -0.22]),
+    Line([-0.15, -0.22]),
+    Line([-0.15,  -0.3]),
+    Line([ 0.15,  -0.3]),
+    Line([ 0.15, -0.12]),
+    Line([ 0.05, -0.12]),
+    Line([ 0.05, -0.05]),
+    Line([-0.05, -0.05]),
+];
+
+pub const SCEDILLA: [&[Segment]; 1] = [
+    &SCEDILLA_CONTOUR_A,
+];
\ No newline at end of file
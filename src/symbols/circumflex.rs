@@ -0,0 +1,18 @@
+use crate::types::Segment::{
+    self,
+    Line,
+};
+
+const SCIRCUMFLEX_CONTOUR_A: [Segment; 7] = [
+    Line([-0.25, 0.1]),
+    Line([-0.15, 0.1]),
+    Line([  0.0, 0.2]),
+    Line([ 0.15, 0.1]),
+    Line([ 0.25, 0.1]),
+    Line([ 0.05, 0.3]),
+    Line([-0.05, 0.3]),
+];
+
+pub const SCIRCUMFLEX: [&[Segment]; 1] = [
+    &SCIRCUMFLEX_CONTOUR_A,
+];
\ No newline at end of file
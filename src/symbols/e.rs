@@ -0,0 +1,23 @@
+use crate::types::Segment::{
+    self,
+    Line,
+};
+
+const SE_CONTOUR_A: [Segment; 12] = [
+    Line([ 0.3, -0.5]),
+    Line([ 0.3, -0.3]),
+    Line([-0.1, -0.3]),
+    Line([-0.1, -0.1]),
+    Line([ 0.2, -0.1]),
+    Line([ 0.2,  0.1]),
+    Line([-0.1,  0.1]),
+    Line([-0.1,  0.3]),
+    Line([ 0.3,  0.3]),
+    Line([ 0.3,  0.5]),
+    Line([-0.3,  0.5]),
+    Line([-0.3, -0.5]),
+];
+
+pub const SE: [&[Segment]; 1] = [
+    &SE_CONTOUR_A,
+];
\ No newline at end of file
@@ -0,0 +1,21 @@
+use crate::types::Segment::{
+    self,
+    Line,
+};
+
+const SF_CONTOUR_A: [Segment; 10] = [
+    Line([-0.1, -0.5]),
+    Line([-0.1, -0.1]),
+    Line([ 0.2, -0.1]),
+    Line([ 0.2,  0.1]),
+    Line([-0.1,  0.1]),
+    Line([-0.1,  0.3]),
+    Line([ 0.3,  0.3]),
+    Line([ 0.3,  0.5]),
+    Line([-0.3,  0.5]),
+    Line([-0.3, -0.5]),
+];
+
+pub const SF: [&[Segment]; 1] = [
+    &SF_CONTOUR_A,
+];
\ No newline at end of file
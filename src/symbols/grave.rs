@@ -0,0 +1,15 @@
+use crate::types::Segment::{
+    self,
+    Line,
+};
+
+const SGRAVE_CONTOUR_A: [Segment; 4] = [
+    Line([ 0.0, 0.1]),
+    Line([ 0.1, 0.1]),
+    Line([-0.1, 0.3]),
+    Line([-0.2, 0.3]),
+];
+
+pub const SGRAVE: [&[Segment]; 1] = [
+    &SGRAVE_CONTOUR_A,
+];
\ No newline at end of file
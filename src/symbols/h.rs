@@ -0,0 +1,23 @@
+use crate::types::Segment::{
+    self,
+    Line,
+};
+
+const SH_CONTOUR_A: [Segment; 12] = [
+    Line([-0.1, -0.5]),
+    Line([-0.1, -0.1]),
+    Line([ 0.1, -0.1]),
+    Line([ 0.1, -0.5]),
+    Line([ 0.3, -0.5]),
+    Line([ 0.3,  0.5]),
+    Line([ 0.1,  0.5]),
+    Line([ 0.1,  0.1]),
+    Line([-0.1,  0.1]),
+    Line([-0.1,  0.5]),
+    Line([-0.3,  0.5]),
+    Line([-0.3, -0.5]),
+];
+
+pub const SH: [&[Segment]; 1] = [
+    &SH_CONTOUR_A,
+];
\ No newline at end of file
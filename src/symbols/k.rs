@@ -0,0 +1,22 @@
+use crate::types::Segment::{
+    self,
+    Line,
+};
+
+const SK_CONTOUR_A: [Segment; 11] = [
+    Line([-0.1, -0.5]),
+    Line([-0.1, -0.1]),
+    Line([ 0.1, -0.5]),
+    Line([ 0.3, -0.5]),
+    Line([ 0.1,  0.0]),
+    Line([ 0.3,  0.5]),
+    Line([ 0.1,  0.5]),
+    Line([-0.1,  0.1]),
+    Line([-0.1,  0.5]),
+    Line([-0.3,  0.5]),
+    Line([-0.3, -0.5]),
+];
+
+pub const SK: [&[Segment]; 1] = [
+    &SK_CONTOUR_A,
+];
\ No newline at end of file
@@ -1,4 +1,41 @@
+pub mod a;
+pub mod b;
+pub mod c;
+pub mod d;
+pub mod e;
+pub mod f;
+pub mod g;
+pub mod h;
 pub mod i;
+pub mod j;
+pub mod k;
 pub mod l;
+pub mod m;
+pub mod n;
+pub mod o;
+pub mod p;
+pub mod q;
+pub mod r;
+pub mod s;
+pub mod t;
 pub mod u;
-pub mod z;
\ No newline at end of file
+pub mod v;
+pub mod w;
+pub mod x;
+pub mod y;
+pub mod z;
+pub mod n0;
+pub mod n1;
+pub mod n2;
+pub mod n3;
+pub mod n4;
+pub mod n5;
+pub mod n6;
+pub mod n7;
+pub mod n8;
+pub mod n9;
+pub mod acute;
+pub mod cedilla;
+pub mod circumflex;
+pub mod grave;
+pub mod tilde;
\ No newline at end of file
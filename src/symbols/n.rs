@@ -0,0 +1,21 @@
+use crate::types::Segment::{
+    self,
+    Line,
+};
+
+const SN_CONTOUR_A: [Segment; 10] = [
+    Line([-0.1, -0.5]),
+    Line([-0.1,  0.1]),
+    Line([ 0.1, -0.5]),
+    Line([ 0.3, -0.5]),
+    Line([ 0.3,  0.5]),
+    Line([ 0.1,  0.5]),
+    Line([ 0.1, -0.1]),
+    Line([-0.1,  0.5]),
+    Line([-0.3,  0.5]),
+    Line([-0.3, -0.5]),
+];
+
+pub const SN: [&[Segment]; 1] = [
+    &SN_CONTOUR_A,
+];
\ No newline at end of file
@@ -0,0 +1,32 @@
+use crate::types::Segment::{
+    self,
+    Cubic,
+    Line,
+};
+
+const S0_CONTOUR_A: [Segment; 8] = [
+    Cubic([0.3, 0.4105], [0.2105, 0.5], [0.1, 0.5]),
+    Line([-0.1, 0.5]),
+    Cubic([-0.2105, 0.5], [-0.3, 0.4105], [-0.3, 0.3]),
+    Line([-0.3, -0.3]),
+    Cubic([-0.3, -0.4105], [-0.2105, -0.5], [-0.1, -0.5]),
+    Line([0.1, -0.5]),
+    Cubic([0.2105, -0.5], [0.3, -0.4105], [0.3, -0.3]),
+    Line([0.3, 0.3]),
+];
+
+const S0_CONTOUR_B: [Segment; 8] = [
+    Line([0.1, -0.2]),
+    Cubic([0.1, -0.2552], [0.0552, -0.3], [0.0, -0.3]),
+    Line([0.0, -0.3]),
+    Cubic([-0.0552, -0.3], [-0.1, -0.2552], [-0.1, -0.2]),
+    Line([-0.1, 0.2]),
+    Cubic([-0.1, 0.2552], [-0.0552, 0.3], [0.0, 0.3]),
+    Line([0.0, 0.3]),
+    Cubic([0.0552, 0.3], [0.1, 0.2552], [0.1, 0.2]),
+];
+
+pub const S0: [&[Segment]; 2] = [
+    &S0_CONTOUR_A,
+    &S0_CONTOUR_B,
+];
\ No newline at end of file
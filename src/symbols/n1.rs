@@ -0,0 +1,22 @@
+use crate::types::Segment::{
+    self,
+    Line,
+};
+
+const S1_CONTOUR_A: [Segment; 11] = [
+    Line([ 0.3, -0.5]),
+    Line([ 0.3, -0.3]),
+    Line([ 0.1, -0.3]),
+    Line([ 0.1,  0.5]),
+    Line([-0.1,  0.5]),
+    Line([-0.3,  0.3]),
+    Line([-0.3,  0.1]),
+    Line([-0.1,  0.3]),
+    Line([-0.1, -0.3]),
+    Line([-0.3, -0.3]),
+    Line([-0.3, -0.5]),
+];
+
+pub const S1: [&[Segment]; 1] = [
+    &S1_CONTOUR_A,
+];
\ No newline at end of file
@@ -0,0 +1,29 @@
+use crate::types::Segment::{
+    self,
+    Cubic,
+    Line,
+};
+
+const S3_CONTOUR_A: [Segment; 17] = [
+    Line([0.1, -0.5]),
+    Cubic([0.2105, -0.5], [0.3, -0.4105], [0.3, -0.3]),
+    Line([0.3, -0.1]),
+    Cubic([0.2448, -0.1], [0.2, -0.0552], [0.2, 0.0]),
+    Cubic([0.2, 0.0552], [0.2448, 0.1], [0.3, 0.1]),
+    Line([0.3, 0.3]),
+    Cubic([0.3, 0.4105], [0.2105, 0.5], [0.1, 0.5]),
+    Line([-0.3, 0.5]),
+    Line([-0.3, 0.3]),
+    Line([0.1, 0.3]),
+    Line([0.1, 0.1]),
+    Line([-0.2, 0.1]),
+    Line([-0.2, -0.1]),
+    Line([0.1, -0.1]),
+    Line([0.1, -0.3]),
+    Line([-0.3, -0.3]),
+    Line([-0.3, -0.5]),
+];
+
+pub const S3: [&[Segment]; 1] = [
+    &S3_CONTOUR_A,
+];
\ No newline at end of file
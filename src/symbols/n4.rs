@@ -0,0 +1,25 @@
+use crate::types::Segment::{
+    self,
+    Line,
+};
+
+const S4_CONTOUR_A: [Segment; 7] = [
+    Line([ 0.3, -0.5]),
+    Line([ 0.3,  0.5]),
+    Line([ 0.1,  0.5]),
+    Line([-0.3, -0.1]),
+    Line([-0.3, -0.3]),
+    Line([ 0.1, -0.3]),
+    Line([ 0.1, -0.5]),
+];
+
+const S4_CONTOUR_B: [Segment; 3] = [
+    Line([ 0.1, -0.1]),
+    Line([-0.1, -0.1]),
+    Line([ 0.1,  0.2]),
+];
+
+pub const S4: [&[Segment]; 2] = [
+    &S4_CONTOUR_A,
+    &S4_CONTOUR_B,
+];
\ No newline at end of file
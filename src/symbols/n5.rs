@@ -0,0 +1,26 @@
+use crate::types::Segment::{
+    self,
+    Cubic,
+    Line,
+};
+
+const S5_CONTOUR_A: [Segment; 14] = [
+    Line([0.1, -0.5]),
+    Cubic([0.2105, -0.5], [0.3, -0.4105], [0.3, -0.3]),
+    Line([0.3, -0.1]),
+    Cubic([0.3, 0.0105], [0.2105, 0.1], [0.1, 0.1]),
+    Line([-0.1, 0.1]),
+    Line([-0.1, 0.3]),
+    Line([0.3, 0.3]),
+    Line([0.3, 0.5]),
+    Line([-0.3, 0.5]),
+    Line([-0.3, -0.1]),
+    Line([0.1, -0.1]),
+    Line([0.1, -0.3]),
+    Line([-0.3, -0.3]),
+    Line([-0.3, -0.5]),
+];
+
+pub const S5: [&[Segment]; 1] = [
+    &S5_CONTOUR_A,
+];
\ No newline at end of file
@@ -0,0 +1,18 @@
+use crate::types::Segment::{
+    self,
+    Line,
+};
+
+const S7_CONTOUR_A: [Segment; 7] = [
+    Line([ 0.1, -0.5]),
+    Line([ 0.3,  0.3]),
+    Line([ 0.3,  0.5]),
+    Line([-0.3,  0.5]),
+    Line([-0.3,  0.3]),
+    Line([ 0.1,  0.3]),
+    Line([-0.1, -0.5]),
+];
+
+pub const S7: [&[Segment]; 1] = [
+    &S7_CONTOUR_A,
+];
\ No newline at end of file
@@ -0,0 +1,42 @@
+use crate::types::Segment::{
+    self,
+    Cubic,
+    Line,
+};
+
+const S8_CONTOUR_A: [Segment; 14] = [
+    Line([0.1, -0.5]),
+    Cubic([0.2105, -0.5], [0.3, -0.4105], [0.3, -0.3]),
+    Line([0.3, -0.1]),
+    Cubic([0.2448, -0.1], [0.2, -0.0552], [0.2, 0.0]),
+    Cubic([0.2, 0.0552], [0.2448, 0.1], [0.3, 0.1]),
+    Line([0.3, 0.3]),
+    Cubic([0.3, 0.4105], [0.2105, 0.5], [0.1, 0.5]),
+    Line([-0.1, 0.5]),
+    Cubic([-0.2105, 0.5], [-0.3, 0.4105], [-0.3, 0.3]),
+    Line([-0.3, 0.1]),
+    Cubic([-0.2448, 0.1], [-0.2, 0.0552], [-0.2, 0.0]),
+    Cubic([-0.2, -0.0552], [-0.2448, -0.1], [-0.3, -0.1]),
+    Line([-0.3, -0.3]),
+    Cubic([-0.3, -0.4105], [-0.2105, -0.5], [-0.1, -0.5]),
+];
+
+const S8_CONTOUR_B: [Segment; 4] = [
+    Cubic([0.1, -0.2552], [0.0552, -0.3], [0.0, -0.3]),
+    Cubic([-0.0552, -0.3], [-0.1, -0.2552], [-0.1, -0.2]),
+    Cubic([-0.1, -0.1448], [-0.0552, -0.1], [0.0, -0.1]),
+    Cubic([0.0552, -0.1], [0.1, -0.1448], [0.1, -0.2]),
+];
+
+const S8_CONTOUR_C: [Segment; 4] = [
+    Cubic([0.1, 0.1448], [0.0552, 0.1], [0.0, 0.1]),
+    Cubic([-0.0552, 0.1], [-0.1, 0.1448], [-0.1, 0.2]),
+    Cubic([-0.1, 0.2552], [-0.0552, 0.3], [0.0, 0.3]),
+    Cubic([0.0552, 0.3], [0.1, 0.2552], [0.1, 0.2]),
+];
+
+pub const S8: [&[Segment]; 3] = [
+    &S8_CONTOUR_A,
+    &S8_CONTOUR_B,
+    &S8_CONTOUR_C,
+];
\ No newline at end of file
@@ -0,0 +1,28 @@
+use crate::types::Segment::{
+    self,
+    Cubic,
+    Line,
+};
+
+const SO_CONTOUR_A: [Segment; 6] = [
+    Cubic([0.3, 0.3657], [0.1657, 0.5], [0.0, 0.5]),
+    Cubic([-0.1657, 0.5], [-0.3, 0.3657], [-0.3, 0.2]),
+    Line([-0.3, -0.2]),
+    Cubic([-0.3, -0.3657], [-0.1657, -0.5], [0.0, -0.5]),
+    Cubic([0.1657, -0.5], [0.3, -0.3657], [0.3, -0.2]),
+    Line([0.3, 0.2]),
+];
+
+const SO_CONTOUR_B: [Segment; 6] = [
+    Line([0.1, -0.2]),
+    Cubic([0.1, -0.2552], [0.0552, -0.3], [0.0, -0.3]),
+    Cubic([-0.0552, -0.3], [-0.1, -0.2552], [-0.1, -0.2]),
+    Line([-0.1, 0.2]),
+    Cubic([-0.1, 0.2552], [-0.0552, 0.3], [0.0, 0.3]),
+    Cubic([0.0552, 0.3], [0.1, 0.2552], [0.1, 0.2]),
+];
+
+pub const SO: [&[Segment]; 2] = [
+    &SO_CONTOUR_A,
+    &SO_CONTOUR_B,
+];
\ No newline at end of file
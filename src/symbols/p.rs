@@ -0,0 +1,29 @@
+use crate::types::Segment::{
+    self,
+    Cubic,
+    Line,
+};
+
+const SP_CONTOUR_A: [Segment; 8] = [
+    Line([-0.1, -0.5]),
+    Line([-0.1, -0.1]),
+    Line([0.1, -0.1]),
+    Cubic([0.2105, -0.1], [0.3, -0.0105], [0.3, 0.1]),
+    Line([0.3, 0.3]),
+    Cubic([0.3, 0.4105], [0.2105, 0.5], [0.1, 0.5]),
+    Line([-0.3, 0.5]),
+    Line([-0.3, -0.5]),
+];
+
+const SP_CONTOUR_B: [Segment; 5] = [
+    Line([-0.1, 0.3]),
+    Line([0.0, 0.3]),
+    Cubic([0.0552, 0.3], [0.1, 0.2552], [0.1, 0.2]),
+    Cubic([0.1, 0.1448], [0.0552, 0.1], [0.0, 0.1]),
+    Line([-0.1, 0.1]),
+];
+
+pub const SP: [&[Segment]; 2] = [
+    &SP_CONTOUR_A,
+    &SP_CONTOUR_B,
+];
\ No newline at end of file
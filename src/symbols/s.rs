@@ -0,0 +1,28 @@
+use crate::types::Segment::{
+    self,
+    Cubic,
+    Line,
+};
+
+const SS_CONTOUR_A: [Segment; 16] = [
+    Line([0.1, -0.5]),
+    Cubic([0.2105, -0.5], [0.3, -0.4105], [0.3, -0.3]),
+    Line([0.3, -0.1]),
+    Cubic([0.3, 0.0105], [0.2105, 0.1], [0.1, 0.1]),
+    Line([-0.1, 0.1]),
+    Line([-0.1, 0.3]),
+    Line([0.3, 0.3]),
+    Line([0.3, 0.5]),
+    Line([-0.1, 0.5]),
+    Cubic([-0.2105, 0.5], [-0.3, 0.4105], [-0.3, 0.3]),
+    Line([-0.3, 0.1]),
+    Cubic([-0.3, -0.0105], [-0.2105, -0.1], [-0.1, -0.1]),
+    Line([0.1, -0.1]),
+    Line([0.1, -0.3]),
+    Line([-0.3, -0.3]),
+    Line([-0.3, -0.5]),
+];
+
+pub const SS: [&[Segment]; 1] = [
+    &SS_CONTOUR_A,
+];
\ No newline at end of file
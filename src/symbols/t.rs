@@ -0,0 +1,19 @@
+use crate::types::Segment::{
+    self,
+    Line,
+};
+
+const ST_CONTOUR_A: [Segment; 8] = [
+    Line([ 0.1, -0.5]),
+    Line([ 0.1,  0.3]),
+    Line([ 0.3,  0.3]),
+    Line([ 0.3,  0.5]),
+    Line([-0.3,  0.5]),
+    Line([-0.3,  0.3]),
+    Line([-0.1,  0.3]),
+    Line([-0.1, -0.5]),
+];
+
+pub const ST: [&[Segment]; 1] = [
+    &ST_CONTOUR_A,
+];
\ No newline at end of file
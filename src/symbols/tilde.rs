@@ -0,0 +1,16 @@
+use crate::types::Segment::{
+    self,
+    Cubic,
+    Line,
+};
+
+const STILDE_CONTOUR_A: [Segment; 4] = [
+    Cubic([-0.1, 0.31], [0.1, 0.01], [0.25, 0.21]),
+    Line([0.25, 0.29]),
+    Cubic([0.1, 0.09], [-0.1, 0.39], [-0.25, 0.19]),
+    Line([-0.25, 0.11]),
+];
+
+pub const STILDE: [&[Segment]; 1] = [
+    &STILDE_CONTOUR_A,
+];
\ No newline at end of file
@@ -0,0 +1,18 @@
+use crate::types::Segment::{
+    self,
+    Line,
+};
+
+const SV_CONTOUR_A: [Segment; 7] = [
+    Line([ 0.1, -0.5]),
+    Line([ 0.3,  0.5]),
+    Line([ 0.1,  0.5]),
+    Line([ 0.0,  0.0]),
+    Line([-0.1,  0.5]),
+    Line([-0.3,  0.5]),
+    Line([-0.1, -0.5]),
+];
+
+pub const SV: [&[Segment]; 1] = [
+    &SV_CONTOUR_A,
+];
\ No newline at end of file
@@ -0,0 +1,23 @@
+use crate::types::Segment::{
+    self,
+    Line,
+};
+
+const SX_CONTOUR_A: [Segment; 12] = [
+    Line([-0.1, -0.5]),
+    Line([ 0.0, -0.2]),
+    Line([ 0.1, -0.5]),
+    Line([ 0.3, -0.5]),
+    Line([ 0.1,  0.0]),
+    Line([ 0.3,  0.5]),
+    Line([ 0.1,  0.5]),
+    Line([ 0.0,  0.2]),
+    Line([-0.1,  0.5]),
+    Line([-0.3,  0.5]),
+    Line([-0.1,  0.0]),
+    Line([-0.3, -0.5]),
+];
+
+pub const SX: [&[Segment]; 1] = [
+    &SX_CONTOUR_A,
+];
\ No newline at end of file
@@ -0,0 +1,20 @@
+use crate::types::Segment::{
+    self,
+    Line,
+};
+
+const SY_CONTOUR_A: [Segment; 9] = [
+    Line([ 0.1, -0.5]),
+    Line([ 0.1, -0.1]),
+    Line([ 0.3,  0.5]),
+    Line([ 0.1,  0.5]),
+    Line([ 0.0,  0.2]),
+    Line([-0.1,  0.5]),
+    Line([-0.3,  0.5]),
+    Line([-0.1, -0.1]),
+    Line([-0.1, -0.5]),
+];
+
+pub const SY: [&[Segment]; 1] = [
+    &SY_CONTOUR_A,
+];
\ No newline at end of file
@@ -0,0 +1,316 @@
+use std::ops::Range;
+
+use crate::{
+    glyph_registry::GlyphRegistry,
+    object::Object,
+    types::Point,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Alignment {
+    Left,
+    Center,
+    Right,
+    Justified,
+}
+
+// caractere do texto com as métricas do glifo usado para desenhá-lo
+struct Letter {
+    symbol: char,
+    // fonte de onde veio o glifo (None para os glifos internos)
+    font: Option<usize>,
+    advance: f64,
+    left_side_bearing: f64,
+    min_x: f64,
+    object: Object,
+}
+
+pub struct Text {
+    letters: Vec<Letter>,
+    // posição do cursor antes de cada letra e depois da última
+    carets: Vec<Point>,
+    pub alignment: Alignment,
+    // distância entre as linhas de base de duas linhas consecutivas
+    pub line_height: f64,
+    // espaço extra entre letras vizinhas
+    pub tracking: f64,
+}
+
+impl Text {
+    pub fn new() -> Self {
+        Self {
+            letters: Vec::new(),
+            carets: vec![[0.0, 0.0]],
+            alignment: Alignment::Left,
+            line_height: 1.2,
+            tracking: 0.0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.letters.len()
+    }
+
+    pub fn object(&self, index: usize) -> &Object {
+        &self.letters[index].object
+    }
+
+    pub fn object_mut(&mut self, index: usize) -> &mut Object {
+        &mut self.letters[index].object
+    }
+
+    // índice do início da linha que contém a posição indicada
+    pub fn line_start(&self, index: usize) -> usize {
+        self.letters[..index]
+            .iter()
+            .rposition(|letter| letter.symbol == '\n')
+            .map_or(0, |newline| newline + 1)
+    }
+
+    // índice da quebra de linha (ou do fim do texto) da linha que contém a
+    // posição indicada
+    pub fn line_end(&self, index: usize) -> usize {
+        self.letters[index..]
+            .iter()
+            .position(|letter| letter.symbol == '\n')
+            .map_or(self.letters.len(), |newline| index + newline)
+    }
+
+    // ponto da linha de base onde o cursor é desenhado
+    pub fn caret_position(&self, index: usize) -> Point {
+        self.carets[index]
+    }
+
+    pub fn set_alignment(&mut self, alignment: Alignment, registry: &GlyphRegistry) {
+        self.alignment = alignment;
+        self.layout(registry);
+    }
+
+    pub fn set_line_height(&mut self, line_height: f64, registry: &GlyphRegistry) {
+        self.line_height = line_height;
+        self.layout(registry);
+    }
+
+    pub fn set_tracking(&mut self, tracking: f64, registry: &GlyphRegistry) {
+        self.tracking = tracking;
+        self.layout(registry);
+    }
+
+    // insere o caractere na posição indicada, se houver glifo para ele;
+    // quebras de linha viram letras vazias, sem avanço
+    pub fn insert(&mut self, index: usize, symbol: char, registry: &mut GlyphRegistry) -> bool {
+        if symbol == '\n' {
+            self.letters.insert(index, Letter {
+                symbol,
+                font: None,
+                advance: 0.0,
+                left_side_bearing: 0.0,
+                min_x: 0.0,
+                object: Object::new([0.0, 0.0, 0.0, 1.0]),
+            });
+            self.layout(registry);
+            return true;
+        }
+
+        let font = registry.font();
+        let glyph = match registry.get(symbol) {
+            Some(glyph) => glyph,
+            None => return false,
+        };
+
+        let mut object = Object::new([0.0, 0.0, 0.0, 1.0]);
+        object.set_outline(&glyph.contours);
+
+        self.letters.insert(index, Letter {
+            symbol,
+            font,
+            advance: glyph.advance,
+            left_side_bearing: glyph.left_side_bearing,
+            min_x: glyph.min[0],
+            object,
+        });
+
+        self.layout(registry);
+        true
+    }
+
+    pub fn remove(&mut self, index: usize, registry: &GlyphRegistry) {
+        if index < self.letters.len() {
+            self.letters.remove(index);
+            self.layout(registry);
+        }
+    }
+
+    // posiciona cada glifo no avanço acumulado dos anteriores da mesma
+    // linha, corrigido pelo ajuste do par e pelo espaçamento entre letras,
+    // e depois alinha as linhas entre si
+    fn layout(&mut self, registry: &GlyphRegistry) {
+        let mut lines: Vec<Range<usize>> = Vec::new();
+        let mut start = 0;
+        for (i, letter) in self.letters.iter().enumerate() {
+            if letter.symbol == '\n' {
+                lines.push(start..i + 1);
+                start = i + 1;
+            }
+        }
+        lines.push(start..self.letters.len());
+
+        let mut pens = vec![0.0; self.letters.len()];
+        // largura até o último glifo visível (espaços no fim não contam)
+        let mut widths: Vec<f64> = Vec::with_capacity(lines.len());
+        let mut visible_ends: Vec<usize> = Vec::with_capacity(lines.len());
+        // avanço ao fim de cada linha
+        let mut ends: Vec<f64> = Vec::with_capacity(lines.len());
+
+        for line in lines.iter() {
+            let mut pen = 0.0;
+            let mut width = 0.0;
+            let mut visible_end = line.start;
+
+            for i in line.clone() {
+                if i > line.start {
+                    pen += self.tracking;
+                    let previous = &self.letters[i - 1];
+                    let current = &self.letters[i];
+                    if previous.font == current.font {
+                        pen += registry.kerning(current.font, previous.symbol, current.symbol);
+                    }
+                }
+
+                pens[i] = pen;
+                pen += self.letters[i].advance;
+
+                if !self.letters[i].symbol.is_whitespace() {
+                    width = pen;
+                    visible_end = i + 1;
+                }
+            }
+
+            widths.push(width);
+            visible_ends.push(visible_end);
+            ends.push(pen);
+        }
+
+        self.carets = vec![[0.0, 0.0]; self.letters.len() + 1];
+
+        let max_width = widths.iter().cloned().fold(0.0, f64::max);
+
+        for (number, line) in lines.iter().enumerate() {
+            let width = widths[number];
+            let visible = line.start..visible_ends[number];
+
+            let offset = match self.alignment {
+                Alignment::Left | Alignment::Justified => 0.0,
+                Alignment::Center => -width / 2.0,
+                Alignment::Right => -width,
+            };
+
+            // no texto justificado, todas as linhas menos a última se
+            // estendem até a largura da maior, alargando os espaços (ou,
+            // sem espaços, o intervalo entre as letras)
+            let spaces = self.letters[visible.clone()]
+                .iter()
+                .filter(|letter| letter.symbol == ' ')
+                .count();
+            let mut space_gap = 0.0;
+            let mut letter_gap = 0.0;
+            if self.alignment == Alignment::Justified && number + 1 < lines.len() {
+                if spaces > 0 {
+                    space_gap = (max_width - width) / spaces as f64;
+                } else if visible.len() > 1 {
+                    letter_gap = (max_width - width) / (visible.len() - 1) as f64;
+                }
+            }
+
+            let y = -(number as f64) * self.line_height;
+            let mut shift = 0.0;
+            for i in line.clone() {
+                self.carets[i] = [offset + pens[i] + shift, y];
+
+                let letter = &mut self.letters[i];
+                letter.object.set_origin([
+                    offset + pens[i] + shift + letter.left_side_bearing - letter.min_x,
+                    y,
+                ]);
+
+                if visible.contains(&i) {
+                    shift += letter_gap;
+                    if letter.symbol == ' ' {
+                        shift += space_gap;
+                    }
+                }
+            }
+
+            if number + 1 == lines.len() {
+                self.carets[self.letters.len()] = [offset + ends[number] + shift, y];
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(symbols: &str, registry: &mut GlyphRegistry) -> Text {
+        let mut text = Text::new();
+        for symbol in symbols.chars() {
+            assert!(text.insert(text.len(), symbol, registry));
+        }
+        text
+    }
+
+    fn advance(symbol: char, registry: &mut GlyphRegistry) -> f64 {
+        registry.get(symbol).unwrap().advance
+    }
+
+    #[test]
+    fn kerning_pulls_pairs_together() {
+        let mut registry = GlyphRegistry::new();
+        let kerned = text("AV", &mut registry);
+        let plain = text("AB", &mut registry);
+
+        let a = advance('A', &mut registry);
+        assert_eq!(plain.caret_position(1), [a, 0.0]);
+        assert!((kerned.caret_position(1)[0] - (a - 0.1)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn tracking_spaces_every_pair() {
+        let mut registry = GlyphRegistry::new();
+        let mut text = text("ABC", &mut registry);
+        let before = text.caret_position(3)[0];
+
+        text.set_tracking(0.05, &registry);
+        assert!((text.caret_position(3)[0] - (before + 0.1)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn newline_moves_the_caret_to_the_next_line() {
+        let mut registry = GlyphRegistry::new();
+        let text = text("AB\nC", &mut registry);
+
+        assert_eq!(text.caret_position(3), [0.0, -text.line_height]);
+        let c = advance('C', &mut registry);
+        assert_eq!(text.caret_position(4), [c, -text.line_height]);
+
+        // o cursor antes da quebra ainda está na primeira linha
+        assert_eq!(text.line_start(2), 0);
+        assert_eq!(text.line_end(2), 2);
+        assert_eq!(text.line_end(0), 2);
+        assert_eq!(text.line_start(3), 3);
+        assert_eq!(text.line_end(3), 4);
+    }
+
+    #[test]
+    fn letters_after_a_removal_move_back() {
+        let mut registry = GlyphRegistry::new();
+        let mut text = text("ABC", &mut registry);
+        let before = text.caret_position(2)[0];
+
+        text.remove(0, &registry);
+        assert_eq!(text.letters[1].symbol, 'C');
+        let a = advance('A', &mut registry);
+        assert!((text.caret_position(1)[0] - (before - a)).abs() < 1e-12);
+    }
+}
@@ -2,6 +2,7 @@ pub struct Texture {
     pub texture: wgpu::Texture,
     pub view: wgpu::TextureView,
     pub format: wgpu::TextureFormat,
+    #[allow(dead_code)]
     pub dimensions: (u32, u32),
 }
 
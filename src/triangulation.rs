@@ -0,0 +1,323 @@
+use crate::types::{
+    FaceType,
+    Point,
+};
+
+const EPSILON: f64 = 1e-12;
+
+// área com sinal (positiva quando o contorno está no sentido anti-horário)
+pub fn signed_area(contour: &[Point]) -> f64 {
+    let mut area = 0.0;
+
+    for i in 0..contour.len() {
+        let a = contour[i];
+        let b = contour[(i + 1) % contour.len()];
+        area += a[0] * b[1] - b[0] * a[1];
+    }
+
+    area / 2.0
+}
+
+fn cross(o: Point, a: Point, b: Point) -> f64 {
+    (a[0] - o[0]) * (b[1] - o[1]) - (a[1] - o[1]) * (b[0] - o[0])
+}
+
+fn in_triangle(p: Point, a: Point, b: Point, c: Point) -> bool {
+    cross(a, b, p) >= -EPSILON &&
+    cross(b, c, p) >= -EPSILON &&
+    cross(c, a, p) >= -EPSILON
+}
+
+fn is_ear(contour: &[Point], indices: &[usize], i: usize) -> bool {
+    let n = indices.len();
+    let a = contour[indices[(i + n - 1) % n]];
+    let b = contour[indices[i]];
+    let c = contour[indices[(i + 1) % n]];
+
+    // vértices reflexos ou colineares não formam orelha
+    if cross(a, b, c) <= EPSILON {
+        return false;
+    }
+
+    // nenhum outro vértice pode estar dentro do triângulo
+    !indices.iter().any(|&j| {
+        let p = contour[j];
+        p != a && p != b && p != c && in_triangle(p, a, b, c)
+    })
+}
+
+fn contains(contour: &[Point], point: Point) -> bool {
+    let mut inside = false;
+
+    for i in 0..contour.len() {
+        let a = contour[i];
+        let b = contour[(i + 1) % contour.len()];
+        if (a[1] > point[1]) != (b[1] > point[1]) &&
+            point[0] < (b[0] - a[0]) * (point[1] - a[1]) / (b[1] - a[1]) + a[0]
+        {
+            inside = !inside;
+        }
+    }
+
+    inside
+}
+
+// conecta um furo (horário) ao polígono externo (anti-horário) por uma
+// ponte entre o vértice mais à direita do furo e um vértice visível dele;
+// falso se nenhuma aresta do polígono estiver à direita do furo, que então
+// não pode ser ligado
+fn merge_hole(polygon: &mut Vec<Point>, hole: &[Point]) -> bool {
+    let m_index = (0..hole.len())
+        .max_by(|&i, &j| hole[i][0].total_cmp(&hole[j][0]))
+        .unwrap();
+    let m = hole[m_index];
+
+    // aresta mais próxima atingida por um raio partindo de m em direção a +x
+    let mut hit: Option<(f64, usize)> = None;
+    for i in 0..polygon.len() {
+        let a = polygon[i];
+        let b = polygon[(i + 1) % polygon.len()];
+        if (a[1] <= m[1]) == (b[1] <= m[1]) {
+            continue;
+        }
+        let x = a[0] + (m[1] - a[1]) * (b[0] - a[0]) / (b[1] - a[1]);
+        if x >= m[0] && hit.is_none_or(|(hit_x, _)| x < hit_x) {
+            hit = Some((x, i));
+        }
+    }
+
+    let (hit_x, edge) = match hit {
+        Some(hit) => hit,
+        None => return false,
+    };
+    let intersection = [hit_x, m[1]];
+
+    let next = (edge + 1) % polygon.len();
+    let mut p_index = if polygon[edge] == intersection || polygon[next] == intersection {
+        if polygon[edge] == intersection { edge } else { next }
+    } else if polygon[edge][0] > polygon[next][0] {
+        edge
+    } else {
+        next
+    };
+
+    // algum vértice dentro do triângulo (m, interseção, p) pode bloquear a
+    // visão; nesse caso escolhe o de menor ângulo em relação ao raio
+    let p = polygon[p_index];
+    let mut best_tangent = f64::MAX;
+    for (i, &v) in polygon.iter().enumerate() {
+        if v == p || v[0] < m[0] {
+            continue;
+        }
+        let inside = if cross(m, intersection, p) > 0.0 {
+            in_triangle(v, m, intersection, p)
+        } else {
+            in_triangle(v, m, p, intersection)
+        };
+        if inside {
+            let tangent = (v[1] - m[1]).abs() / (v[0] - m[0]).max(EPSILON);
+            if tangent < best_tangent {
+                best_tangent = tangent;
+                p_index = i;
+            }
+        }
+    }
+
+    let mut bridge: Vec<Point> = Vec::with_capacity(hole.len() + 2);
+    bridge.extend_from_slice(&hole[m_index..]);
+    bridge.extend_from_slice(&hole[..=m_index]);
+    bridge.push(polygon[p_index]);
+
+    let tail = polygon.split_off(p_index + 1);
+    polygon.extend(bridge);
+    polygon.extend(tail);
+    true
+}
+
+// triangula um conjunto de contornos fechados; contornos contidos em um
+// número ímpar de outros são furos do contorno imediatamente externo
+pub fn triangulate<C: AsRef<[Point]>>(contours: &[C]) -> Vec<FaceType> {
+    let contours: Vec<&[Point]> = contours
+        .iter()
+        .map(|contour| contour.as_ref())
+        .filter(|contour| contour.len() >= 3)
+        .collect();
+
+    let depths: Vec<usize> = contours
+        .iter()
+        .enumerate()
+        .map(|(i, contour)| {
+            contours
+                .iter()
+                .enumerate()
+                .filter(|&(j, other)| i != j && contains(other, contour[0]))
+                .count()
+        })
+        .collect();
+
+    let mut faces: Vec<FaceType> = Vec::new();
+
+    for (i, outer) in contours.iter().enumerate() {
+        if depths[i] % 2 == 1 {
+            continue;
+        }
+
+        let mut polygon = outer.to_vec();
+        if signed_area(&polygon) < 0.0 {
+            polygon.reverse();
+        }
+
+        // furos cujo contorno externo mais próximo é este
+        let mut holes: Vec<Vec<Point>> = contours
+            .iter()
+            .enumerate()
+            .filter(|&(j, hole)| {
+                depths[j] == depths[i] + 1 &&
+                contains(outer, hole[0]) &&
+                !contours.iter().enumerate().any(|(k, other)|
+                    k != i &&
+                    depths[k] == depths[i] &&
+                    contains(other, hole[0]) &&
+                    signed_area(other).abs() < signed_area(outer).abs()
+                )
+            })
+            .map(|(_, hole)| {
+                let mut hole = hole.to_vec();
+                if signed_area(&hole) > 0.0 {
+                    hole.reverse();
+                }
+                hole
+            })
+            .collect();
+
+        holes.sort_by(|a, b| {
+            let a_max = a.iter().map(|p| p[0]).fold(f64::MIN, f64::max);
+            let b_max = b.iter().map(|p| p[0]).fold(f64::MIN, f64::max);
+            b_max.total_cmp(&a_max)
+        });
+
+        for hole in holes.iter() {
+            if !merge_hole(&mut polygon, hole) {
+                // só acontece com o furo encostado ou fora do contorno; o
+                // furo acaba preenchido
+                log::warn!(
+                    "furo com vértice mais à direita em {:?} não foi ligado ao contorno externo",
+                    hole.iter().max_by(|a, b| a[0].total_cmp(&b[0])),
+                );
+            }
+        }
+
+        faces.extend(triangulate_polygon(&polygon));
+    }
+
+    faces
+}
+
+fn to_face(a: Point, b: Point, c: Point) -> FaceType {
+    (
+        [a[0], a[1], 0.0, 1.0],
+        [b[0], b[1], 0.0, 1.0],
+        [c[0], c[1], 0.0, 1.0],
+    )
+}
+
+// triangula um polígono fechado (já sem furos) por recorte de orelhas,
+// gerando faces no sentido anti-horário
+fn triangulate_polygon(contour: &[Point]) -> Vec<FaceType> {
+    let mut faces: Vec<FaceType> = Vec::new();
+
+    if contour.len() < 3 {
+        return faces;
+    }
+
+    let mut indices: Vec<usize> = (0..contour.len()).collect();
+    if signed_area(contour) < 0.0 {
+        indices.reverse();
+    }
+
+    while indices.len() > 3 {
+        match (0..indices.len()).find(|&i| is_ear(contour, &indices, i)) {
+            Some(i) => {
+                let n = indices.len();
+                faces.push(to_face(
+                    contour[indices[(i + n - 1) % n]],
+                    contour[indices[i]],
+                    contour[indices[(i + 1) % n]],
+                ));
+                indices.remove(i);
+            }
+            None => {
+                // contorno degenerado: descarta um vértice colinear,
+                // ou desiste se não houver nenhum
+                let n = indices.len();
+                match (0..n).find(|&i| cross(
+                    contour[indices[(i + n - 1) % n]],
+                    contour[indices[i]],
+                    contour[indices[(i + 1) % n]],
+                ).abs() <= EPSILON) {
+                    Some(i) => {
+                        indices.remove(i);
+                    }
+                    None => {
+                        return faces;
+                    }
+                }
+            }
+        }
+    }
+
+    let (a, b, c) = (contour[indices[0]], contour[indices[1]], contour[indices[2]]);
+    if cross(a, b, c) > EPSILON {
+        faces.push(to_face(a, b, c));
+    }
+
+    faces
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        constants::DEFAULT_TOLERANCE,
+        outline::flatten,
+        symbols::{a, b, n8, q},
+        types::Segment,
+    };
+
+    #[test]
+    fn holes_are_left_open() {
+        let glyphs: [(char, &[&[Segment]]); 4] = [
+            ('A', &a::SA),
+            ('B', &b::SB),
+            ('8', &n8::S8),
+            ('Q', &q::SQ),
+        ];
+
+        for (symbol, contours) in glyphs {
+            let polygons: Vec<Vec<Point>> = contours
+                .iter()
+                .map(|contour| flatten(contour, DEFAULT_TOLERANCE))
+                .collect();
+
+            // contornos dentro de outro são furos
+            let expected: f64 = polygons
+                .iter()
+                .map(|polygon| {
+                    let inside = polygons
+                        .iter()
+                        .filter(|other| *other != polygon && contains(other, polygon[0]))
+                        .count();
+                    let area = signed_area(polygon).abs();
+                    if inside % 2 == 1 { -area } else { area }
+                })
+                .sum();
+            assert!(polygons.len() > 1 && expected < signed_area(&polygons[0]).abs());
+
+            let area: f64 = triangulate(&polygons)
+                .iter()
+                .map(|(a, b, c)| cross([a[0], a[1]], [b[0], b[1]], [c[0], c[1]]) / 2.0)
+                .sum();
+            assert!((area - expected).abs() < 1e-9, "glifo {:?}: {} != {}", symbol, area, expected);
+        }
+    }
+}
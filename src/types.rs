@@ -1,17 +1,50 @@
-use nalgebra::SMatrix;
+use nalgebra::{
+    SMatrix,
+    UnitQuaternion,
+};
 
+pub type Point = [f64; 2];
 pub type Position = [f64; 4];
 pub type Scale = [f64; 3];
+// ângulos de Euler, em graus
 pub type Rotation = [f64; 3];
+pub type Quaternion = UnitQuaternion<f64>;
 
-pub type Matrix4x1 = SMatrix<f64, 4, 1>;
+pub type Matrix3x3 = SMatrix<f64, 3, 3>;
 pub type Matrix4x4 = SMatrix<f64, 4, 4>;
 
 pub type FaceType = (Position, Position, Position);
 
+// trecho de um contorno fechado, partindo do ponto final do trecho anterior
+// (o primeiro trecho parte do ponto final do último)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Segment {
+    Line(Point),
+    // ponto de controle, ponto final
+    Quadratic(Point, Point),
+    // dois pontos de controle, ponto final
+    Cubic(Point, Point, Point),
+}
+
 #[repr(C)]
 #[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
 pub struct ShaderVertex {
     pub position: [f32; 3],
     pub color: [f32; 3],
+    // nulo nos vértices usados só pelas linhas
+    pub normal: [f32; 3],
+}
+
+// dados de cada objeto desenhado, lidos uma vez por instância
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct ShaderInstance {
+    // matriz de modelo, em colunas
+    pub model: [[f32; 4]; 4],
+    // multiplicada pela cor dos vértices
+    pub color: [f32; 4],
+    // bits INSTANCE_* (seleção, mouse e forma de destaque)
+    pub state: u32,
+    // matriz das normais, em colunas; só usada pelas faces
+    pub normal: [[f32; 3]; 3],
 }
\ No newline at end of file
@@ -0,0 +1,227 @@
+use std::{
+    collections::HashMap,
+    fmt,
+};
+
+use crate::mesh::Mesh;
+
+const EPSILON: f64 = 1e-12;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Problem {
+    // duas faces com os mesmos três vértices
+    DuplicateFace { faces: [usize; 2] },
+    // face com área nula
+    DegenerateFace { face: usize },
+    // duas faces percorrem a aresta compartilhada no mesmo sentido
+    InconsistentWinding { edge: [usize; 2], faces: [usize; 2] },
+    // aresta compartilhada por mais de duas faces
+    NonManifoldEdge { edge: [usize; 2], faces: Vec<usize> },
+    // vértice no meio de uma aresta de borda, sem dividi-la
+    TJunction { vertex: usize, edge: [usize; 2] },
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Problem::DuplicateFace { faces } => {
+                write!(f, "Faces {} e {} repetidas", faces[0], faces[1])
+            }
+            Problem::DegenerateFace { face } => {
+                write!(f, "Face {} sem área", face)
+            }
+            Problem::InconsistentWinding { edge, faces } => write!(
+                f,
+                "Faces {} e {} com orientações opostas na aresta {}-{}",
+                faces[0], faces[1], edge[0], edge[1],
+            ),
+            Problem::NonManifoldEdge { edge, faces } => write!(
+                f,
+                "Aresta {}-{} compartilhada por {} faces",
+                edge[0], edge[1], faces.len(),
+            ),
+            Problem::TJunction { vertex, edge } => write!(
+                f,
+                "Vértice {} sobre a aresta {}-{}",
+                vertex, edge[0], edge[1],
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ValidationReport {
+    pub problems: Vec<Problem>,
+}
+
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
+
+// arestas na ordem dos seus vértices, para um relatório estável
+fn sorted(mesh: &Mesh, indices: impl Iterator<Item = usize>) -> Vec<([usize; 2], usize)> {
+    let mut edges: Vec<([usize; 2], usize)> = indices
+        .map(|index| {
+            let [a, b] = mesh.edges()[index].vertices;
+            ([a.min(b), a.max(b)], index)
+        })
+        .collect();
+    edges.sort();
+    edges
+}
+
+pub fn validate(mesh: &Mesh) -> ValidationReport {
+    let mut report = ValidationReport::default();
+    let faces: Vec<[usize; 3]> = (0..mesh.faces().len())
+        .map(|face| mesh.face_vertices(face))
+        .collect();
+    let position = |index: usize| mesh.vertices()[index].position;
+
+    let mut known_faces: HashMap<[usize; 3], usize> = HashMap::new();
+    for (face, vertices) in faces.iter().enumerate() {
+        let mut key = *vertices;
+        key.sort();
+        match known_faces.get(&key) {
+            Some(&other) => report.problems.push(Problem::DuplicateFace { faces: [other, face] }),
+            None => {
+                known_faces.insert(key, face);
+            }
+        }
+
+        let [a, b, c] = vertices.map(position);
+        let cross = (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0]);
+        if cross.abs() <= EPSILON {
+            report.problems.push(Problem::DegenerateFace { face });
+        }
+    }
+
+    // duas faces bem orientadas têm semiarestas opostas, ligadas como gêmeas
+    for (edge, index) in sorted(mesh, mesh.interior_edges()) {
+        let half_edges = &mesh.edges()[index].half_edges;
+        if mesh.half_edge(half_edges[0]).twin != Some(half_edges[1]) {
+            report.problems.push(Problem::InconsistentWinding {
+                edge,
+                faces: [
+                    mesh.half_edge(half_edges[0]).face,
+                    mesh.half_edge(half_edges[1]).face,
+                ],
+            });
+        }
+    }
+
+    // só vértices na borda de algum leque podem ficar sobre uma aresta de borda
+    let boundary_vertices: Vec<usize> = (0..mesh.vertices().len())
+        .filter(|&vertex| mesh.is_boundary_vertex(vertex))
+        .collect();
+
+    let others = (0..mesh.edges().len()).filter(|&index| mesh.edges()[index].half_edges.len() != 2);
+    for (edge, index) in sorted(mesh, others) {
+        match mesh.edges()[index].half_edges.len() {
+            1 => {
+                // vértices no interior de uma aresta de borda
+                let (a, b) = (position(edge[0]), position(edge[1]));
+                let length = (b[0] - a[0]).hypot(b[1] - a[1]);
+                for &vertex in boundary_vertices.iter() {
+                    if vertex == edge[0] || vertex == edge[1] {
+                        continue;
+                    }
+                    let p = position(vertex);
+                    let cross = (b[0] - a[0]) * (p[1] - a[1]) - (b[1] - a[1]) * (p[0] - a[0]);
+                    let t = ((p[0] - a[0]) * (b[0] - a[0]) + (p[1] - a[1]) * (b[1] - a[1])) /
+                        (length * length);
+                    if cross.abs() <= EPSILON.sqrt() * length && t > 0.0 && t < 1.0 {
+                        report.problems.push(Problem::TJunction { vertex, edge });
+                    }
+                }
+            }
+            _ => {
+                report.problems.push(Problem::NonManifoldEdge {
+                    edge,
+                    faces: mesh.edge_faces(index).collect(),
+                });
+            }
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mesh(faces: &[[[f64; 2]; 3]]) -> Mesh {
+        let mut mesh = Mesh::new();
+        for [a, b, c] in faces {
+            mesh.add_face((
+                [a[0], a[1], 0.0, 1.0],
+                [b[0], b[1], 0.0, 1.0],
+                [c[0], c[1], 0.0, 1.0],
+            ));
+        }
+        mesh
+    }
+
+    #[test]
+    fn square_is_valid() {
+        let report = validate(&mesh(&[
+            [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0]],
+            [[0.0, 0.0], [1.0, 1.0], [0.0, 1.0]],
+        ]));
+        assert!(report.is_valid(), "{:?}", report.problems);
+    }
+
+    #[test]
+    fn finds_duplicate_faces() {
+        let report = validate(&mesh(&[
+            [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0]],
+            [[1.0, 0.0], [1.0, 1.0], [0.0, 0.0]],
+        ]));
+        assert!(report.problems.contains(&Problem::DuplicateFace { faces: [0, 1] }));
+    }
+
+    #[test]
+    fn finds_degenerate_faces() {
+        let report = validate(&mesh(&[
+            [[0.0, 0.0], [1.0, 0.0], [2.0, 0.0]],
+        ]));
+        assert!(report.problems.contains(&Problem::DegenerateFace { face: 0 }));
+    }
+
+    #[test]
+    fn finds_inconsistent_winding() {
+        let report = validate(&mesh(&[
+            [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0]],
+            [[0.0, 0.0], [0.0, 1.0], [1.0, 1.0]],
+        ]));
+        assert_eq!(report.problems, vec![Problem::InconsistentWinding {
+            edge: [0, 2],
+            faces: [0, 1],
+        }]);
+    }
+
+    #[test]
+    fn finds_non_manifold_edges() {
+        let report = validate(&mesh(&[
+            [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]],
+            [[1.0, 0.0], [0.0, 0.0], [0.0, -1.0]],
+            [[1.0, 0.0], [0.0, 0.0], [1.0, -1.0]],
+        ]));
+        assert!(report.problems.contains(&Problem::NonManifoldEdge {
+            edge: [0, 1],
+            faces: vec![0, 1, 2],
+        }));
+    }
+
+    #[test]
+    fn finds_t_junctions() {
+        let report = validate(&mesh(&[
+            [[0.0, 0.0], [2.0, 0.0], [1.0, 1.0]],
+            [[0.0, 0.0], [1.0, -1.0], [1.0, 0.0]],
+            [[1.0, 0.0], [1.0, -1.0], [2.0, 0.0]],
+        ]));
+        assert!(report.problems.contains(&Problem::TJunction { vertex: 4, edge: [0, 1] }));
+    }
+}